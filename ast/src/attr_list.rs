@@ -36,6 +36,27 @@ impl<'arena> AttrList<'arena> {
     self.positional.push(None);
   }
 
+  /// merges a subsequent attr list for the same block (e.g. `[[id]]` then
+  /// `[.role]`): roles and options accumulate, later values win otherwise
+  pub fn merge(&mut self, other: AttrList<'arena>) {
+    if other.id.is_some() {
+      self.id = other.id;
+    }
+    self.roles.extend(other.roles);
+    self.options.extend(other.options);
+    for (idx, positional) in other.positional.into_iter().enumerate() {
+      if idx >= self.positional.len() {
+        self.positional.push(positional);
+      } else if positional.is_some() {
+        self.positional[idx] = positional;
+      }
+    }
+    for (key, value) in other.named.0 {
+      self.named.0.retain(|(existing, _)| existing != &key);
+      self.named.insert(key, value);
+    }
+  }
+
   /// https://docs.asciidoctor.org/asciidoc/latest/blocks/#block-style
   pub fn block_style(&self) -> Option<BlockContext> {
     if let Some(first_positional) = self.str_positional_at(0) {
@@ -19,6 +19,14 @@ impl<'arena> Block<'arena> {
   pub fn named_attr(&self, name: &str) -> Option<&str> {
     self.meta.attrs.as_ref().and_then(|attrs| attrs.named(name))
   }
+
+  pub fn has_role(&self, role: &str) -> bool {
+    self
+      .meta
+      .attrs
+      .as_ref()
+      .is_some_and(|attrs| attrs.has_role(role))
+  }
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -49,6 +57,14 @@ pub enum EmptyMetadata<'arena> {
     target: SourceString<'arena>,
     attrs: AttrList<'arena>,
   },
+  Audio {
+    target: SourceString<'arena>,
+    attrs: AttrList<'arena>,
+  },
+  Video {
+    target: SourceString<'arena>,
+    attrs: AttrList<'arena>,
+  },
   DiscreteHeading {
     level: u8,
     content: InlineNodes<'arena>,
@@ -84,6 +100,7 @@ pub enum BlockContext {
   QuotedParagraph,
   Section,
   Sidebar,
+  Stem,
   Table,
   TableCell,
   ThematicBreak,
@@ -137,6 +154,7 @@ impl BlockContext {
       "listing" | "source" => Some(BlockContext::Listing),
       "literal" => Some(BlockContext::Literal),
       "pass" => Some(BlockContext::Passthrough),
+      "stem" | "latexmath" | "asciimath" => Some(BlockContext::Stem),
       "comment" => Some(BlockContext::Comment),
       "verse" => Some(BlockContext::Verse),
       "example" => Some(BlockContext::Example),
@@ -182,6 +200,20 @@ impl Json for EmptyMetadata<'_> {
           buf.add_member("attrs", attrs);
         }
       }
+      EmptyMetadata::Audio { target, attrs } => {
+        buf.push_str("Audio\"");
+        buf.add_member("target", target);
+        if !attrs.is_empty() {
+          buf.add_member("attrs", attrs);
+        }
+      }
+      EmptyMetadata::Video { target, attrs } => {
+        buf.push_str("Video\"");
+        buf.add_member("target", target);
+        if !attrs.is_empty() {
+          buf.add_member("attrs", attrs);
+        }
+      }
       EmptyMetadata::DiscreteHeading { level, content, id } => {
         buf.push_str("DiscreteHeading\"");
         buf.add_member("level", level);
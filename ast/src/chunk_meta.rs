@@ -5,11 +5,18 @@ pub struct ChunkMeta<'arena> {
   pub attrs: Option<AttrList<'arena>>,
   pub title: Option<InlineNodes<'arena>>,
   pub start: u32, // rename
+  /// caption number assigned by the parser, e.g. `2` in `Figure 2.`
+  pub numeral: Option<usize>,
 }
 
 impl<'arena> ChunkMeta<'arena> {
   pub const fn empty(start: u32) -> Self {
-    Self { title: None, attrs: None, start }
+    Self {
+      title: None,
+      attrs: None,
+      start,
+      numeral: None,
+    }
   }
 
   pub const fn new(
@@ -17,7 +24,7 @@ impl<'arena> ChunkMeta<'arena> {
     title: Option<InlineNodes<'arena>>,
     start: u32,
   ) -> Self {
-    Self { title, attrs, start }
+    Self { title, attrs, start, numeral: None }
   }
 
   pub fn has_attr_option(&self, name: &str) -> bool {
@@ -41,6 +48,7 @@ impl Json for ChunkMeta<'_> {
     buf.begin_obj("ChunkMeta");
     buf.add_option_member("attrs", self.attrs.as_ref());
     buf.add_option_member("title", self.title.as_ref());
+    buf.add_option_member("numeral", self.numeral.as_ref());
     buf.finish_obj();
   }
 }
@@ -36,12 +36,13 @@ impl<'arena> ColWidths<'arena> {
   }
 
   pub fn distribute(&self) -> impl ExactSizeIterator<Item = DistributedColWidth> + '_ {
-    let mut width_divisor = Option::<u8>::None;
+    // NB: sum in a wider int, specs like `cols="90%,90%,90%"` overflow a u8
+    let mut width_divisor = Option::<u32>::None;
     let mut num_autowidth = 0;
     for cw in self.iter() {
       match cw {
         ColWidth::Proportional(n) | ColWidth::Percentage(n) => {
-          width_divisor = Some(width_divisor.map_or(*n, |sum| sum + n))
+          width_divisor = Some(width_divisor.map_or(*n as u32, |sum| sum + *n as u32))
         }
         ColWidth::Auto => num_autowidth += 1,
       }
@@ -73,6 +74,18 @@ fn test_distribute_col_width() {
   use DistributedColWidth as DCW;
   let cases: &[(&[CW], &[DCW])] = &[
     (&[CW::Proportional(1)], &[DCW::Percentage(100.0)]),
+    (
+      &[CW::Percentage(25), CW::Proportional(75)],
+      &[DCW::Percentage(25.0), DCW::Percentage(75.0)],
+    ),
+    (
+      &[CW::Percentage(150), CW::Percentage(150)],
+      &[DCW::Percentage(50.0), DCW::Percentage(50.0)],
+    ),
+    (
+      &[CW::Percentage(200), CW::Auto],
+      &[DCW::Percentage(100.0), DCW::Auto(0.0)],
+    ),
     (
       &[CW::Auto, CW::Auto, CW::Auto],
       &[
@@ -121,6 +134,10 @@ fn test_distribute_col_width() {
       &[CW::Proportional(1), CW::Proportional(2), CW::Auto],
       &[DCW::Percentage(1.0), DCW::Percentage(2.0), DCW::Auto(97.0)],
     ),
+    (
+      &[CW::Proportional(1), CW::Auto, CW::Proportional(2)],
+      &[DCW::Percentage(1.0), DCW::Auto(97.0), DCW::Percentage(2.0)],
+    ),
     (
       &[CW::Proportional(25), CW::Auto, CW::Auto],
       &[DCW::Percentage(25.0), DCW::Auto(37.5), DCW::Auto(37.5)],
@@ -12,6 +12,8 @@ pub struct Document<'arena> {
   pub content: DocContent<'arena>,
   pub toc: Option<TableOfContents<'arena>>,
   pub anchors: Rc<RefCell<HashMap<BumpString<'arena>, Anchor<'arena>>>>,
+  /// numbers of footnotes defined with an id, for resolving references
+  pub footnotes: Rc<RefCell<HashMap<BumpString<'arena>, u16>>>,
   pub source_filenames: Vec<String>,
 }
 
@@ -27,6 +29,7 @@ impl<'arena> Document<'arena> {
       content,
       toc: None,
       anchors: Rc::new(RefCell::new(HashMap::new())),
+      footnotes: Rc::new(RefCell::new(HashMap::new())),
       meta: DocumentMeta::default(),
       source_filenames: Vec::new(),
     }
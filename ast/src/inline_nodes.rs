@@ -21,6 +21,20 @@ impl<'arena> InlineNodes<'arena> {
       Inline::CurlyQuote(LegacyImplicitApostrophe) => text.push("'"),
       Inline::Discarded => {}
       Inline::Highlight(nodes) => text.extend(nodes.plain_text()),
+      Inline::Macro(MacroNode::Stem { text: stem, .. }) => text.push(stem.src.as_str()),
+      Inline::Macro(MacroNode::Image { target, attrs, .. }) => {
+        push_image_alt(&mut text, target, attrs)
+      }
+      Inline::Macro(MacroNode::Button(btn)) => text.push(btn.src.as_str()),
+      Inline::Macro(MacroNode::Link { target, attrs, .. }) => {
+        match attrs.as_ref().and_then(|attrs| attrs.positional.first()) {
+          Some(Some(nodes)) => text.extend(nodes.plain_text()),
+          _ => text.push(target.src.as_str()),
+        }
+      }
+      Inline::Macro(MacroNode::Xref { linktext: Some(nodes), .. }) => {
+        text.extend(nodes.plain_text())
+      }
       Inline::Macro(_) => {}
       Inline::Italic(nodes) => text.extend(nodes.plain_text()),
       Inline::InlinePassthru(nodes) => text.extend(nodes.plain_text()),
@@ -111,6 +125,24 @@ impl Json for InlineNodes<'_> {
   }
 }
 
+// same fallback as asciidoctor: the file stem, w/ `-` and `_` as spaces
+fn push_image_alt<'a>(text: &mut Vec<&'a str>, target: &'a str, attrs: &'a AttrList) {
+  if let Some(alt) = attrs.named("alt") {
+    text.push(alt);
+  } else if let Some(Some(nodes)) = attrs.positional.first() {
+    text.extend(nodes.plain_text());
+  } else {
+    let basename = target.rsplit('/').next().unwrap_or(target);
+    let stem = basename.rsplit_once('.').map_or(basename, |(stem, _)| stem);
+    for (idx, word) in stem.split(['-', '_']).enumerate() {
+      if idx > 0 {
+        text.push(" ");
+      }
+      text.push(word);
+    }
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -125,4 +157,24 @@ mod tests {
     ];
     expect_eq!(heading.plain_text(), vec!["Document", " ", "title"]);
   }
+
+  #[test]
+  fn test_plain_text_macros() {
+    let heading: InlineNodes = nodes![
+      node!(
+        Inline::Macro(MacroNode::Image {
+          flow: Flow::Inline,
+          target: src!("img/the-logo_v2.png", 6..25),
+          attrs: AttrList::new(SourceLocation::new(25, 27), leaked_bump()),
+        }),
+        0..27,
+      ),
+      node!(" "; 27..28),
+      node!(Inline::Macro(MacroNode::Button(src!("OK", 33..35))), 28..36),
+    ];
+    expect_eq!(
+      heading.plain_text(),
+      vec!["the", " ", "logo", " ", "v2", " ", "OK"]
+    );
+  }
 }
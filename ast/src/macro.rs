@@ -3,6 +3,8 @@ use crate::internal::*;
 #[derive(Debug, PartialEq, Eq, Clone)]
 pub enum MacroNode<'arena> {
   Footnote {
+    /// `0` for a reference (`footnote:id[]`), which is resolved through
+    /// `Document::footnotes` so that it may precede its definition
     number: u16,
     id: Option<SourceString<'arena>>,
     text: InlineNodes<'arena>,
@@ -27,6 +29,10 @@ pub enum MacroNode<'arena> {
     attrs: AttrList<'arena>,
   },
   Button(SourceString<'arena>),
+  Stem {
+    name: &'static str,
+    text: SourceString<'arena>,
+  },
   Menu(BumpVec<'arena, SourceString<'arena>>),
   Xref {
     id: SourceString<'arena>,
@@ -50,6 +56,45 @@ pub enum Flow {
   Block,
 }
 
+/// the target of an xref into another document, e.g. `other.adoc#id`
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct InterdocTarget<'a> {
+  pub path: &'a str,
+  pub fragment: Option<&'a str>,
+}
+
+impl<'a> InterdocTarget<'a> {
+  /// returns `None` for references within the current document
+  pub fn parse(target: &'a str) -> Option<Self> {
+    match target.split_once('#') {
+      Some(("", _)) => None,
+      Some((path, fragment)) => Some(InterdocTarget {
+        path,
+        fragment: Some(fragment).filter(|fragment| !fragment.is_empty()),
+      }),
+      None if target.ends_with(".adoc") => Some(InterdocTarget { path: target, fragment: None }),
+      None => None,
+    }
+  }
+
+  /// the converted document path, w/ `.adoc` replaced by `outfilesuffix`,
+  /// or `default_suffix` if the attribute is not set
+  pub fn out_path(&self, meta: &DocumentMeta, default_suffix: &str) -> String {
+    let stem = self.path.strip_suffix(".adoc").unwrap_or(self.path);
+    format!(
+      "{stem}{}",
+      meta.str("outfilesuffix").unwrap_or(default_suffix)
+    )
+  }
+
+  pub fn href(&self, meta: &DocumentMeta, default_suffix: &str) -> String {
+    match self.fragment {
+      Some(fragment) => format!("{}#{fragment}", self.out_path(meta, default_suffix)),
+      None => self.out_path(meta, default_suffix),
+    }
+  }
+}
+
 impl Json for Flow {
   fn to_json_in(&self, buf: &mut JsonBuf) {
     buf.push_obj_enum_type("Flow", self);
@@ -99,6 +144,11 @@ impl Json for MacroNode<'_> {
         buf.push_str("Button\"");
         buf.add_member("text", s);
       }
+      MacroNode::Stem { name, text } => {
+        buf.push_str("Stem\"");
+        buf.add_member("name", name);
+        buf.add_member("text", text);
+      }
       MacroNode::Menu(items) => {
         buf.push_str("Menu\"");
         buf.add_member("items", items);
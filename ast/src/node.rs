@@ -6,6 +6,8 @@ use crate::internal::*;
 pub struct Anchor<'arena> {
   pub reftext: Option<InlineNodes<'arena>>,
   pub title: InlineNodes<'arena>,
+  /// caption attr name (e.g. `figure-caption`) and number, for numbered blocks
+  pub numeral: Option<(&'static str, usize)>,
 }
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -20,7 +22,7 @@ pub struct Section<'arena> {
 #[derive(Default, Clone, Copy, Eq, PartialEq)]
 pub struct Callout {
   /// list index, e.g. `0` maps to `1` in dr id: `CO1-3`
-  pub list_idx: u8,
+  pub list_idx: u16,
   /// callout index w/in list, e.g. `2` maps to `3` in dr id: `CO1-3`
   pub callout_idx: u8,
   /// the reader-facing callout number, i.e. `1` in `<1>`
@@ -28,7 +30,7 @@ pub struct Callout {
 }
 
 impl Callout {
-  pub const fn new(list_idx: u8, callout_idx: u8, number: u8) -> Self {
+  pub const fn new(list_idx: u16, callout_idx: u8, number: u8) -> Self {
     Self { list_idx, callout_idx, number }
   }
 }
@@ -12,6 +12,10 @@ pub struct TocNode<'arena> {
   pub level: u8,
   pub title: InlineNodes<'arena>,
   pub id: Option<BumpString<'arena>>,
+  /// special section style, e.g. `appendix`, from the section attrs
+  pub special_sect: Option<BumpString<'arena>>,
+  /// section has the `%unnumbered` option
+  pub unnumbered: bool,
   pub children: BumpVec<'arena, TocNode<'arena>>,
 }
 
@@ -40,6 +44,8 @@ impl Json for TocNode<'_> {
     buf.add_member("level", &self.level);
     buf.add_member("title", &self.title);
     buf.add_option_member("id", self.id.as_ref());
+    buf.add_option_member("special_sect", self.special_sect.as_ref());
+    buf.add_member("unnumbered", &self.unnumbered);
     buf.add_member("children", &self.children);
     buf.finish_obj();
   }
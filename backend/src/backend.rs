@@ -65,8 +65,14 @@ pub trait Backend {
   fn exit_literal_block(&mut self, block: &Block, content: &BlockContent);
   fn enter_passthrough_block(&mut self, block: &Block, content: &BlockContent);
   fn exit_passthrough_block(&mut self, block: &Block, content: &BlockContent);
+  fn enter_stem_block(&mut self, block: &Block, content: &BlockContent);
+  fn exit_stem_block(&mut self, block: &Block, content: &BlockContent);
   fn enter_image_block(&mut self, img_target: &str, img_attrs: &AttrList, block: &Block);
   fn exit_image_block(&mut self, block: &Block);
+  fn enter_audio_block(&mut self, target: &str, attrs: &AttrList, block: &Block);
+  fn exit_audio_block(&mut self, block: &Block);
+  fn enter_video_block(&mut self, target: &str, attrs: &AttrList, block: &Block);
+  fn exit_video_block(&mut self, block: &Block);
   fn enter_admonition_block(&mut self, kind: AdmonitionKind, block: &Block);
   fn exit_admonition_block(&mut self, kind: AdmonitionKind, block: &Block);
   fn enter_quoted_paragraph(&mut self, block: &Block, attr: &str, cite: Option<&str>);
@@ -117,12 +123,18 @@ pub trait Backend {
   fn visit_page_break(&mut self, block: &Block);
 
   /// inlines
+  ///
+  /// NB: `text` is safe to emit as-is: whenever the special chars substitution
+  /// is active, `&`, `<` and `>` are split out upstream into `SpecialChar` nodes
+  /// (see `visit_inline_specialchar`), so they can only reach this method when
+  /// special chars are disabled (passthroughs, `subs=none`), where raw is intended
   fn visit_inline_text(&mut self, text: &str);
   fn visit_inline_lit_mono(&mut self, text: &str);
   fn visit_joining_newline(&mut self);
   fn visit_curly_quote(&mut self, kind: CurlyKind);
   fn visit_multichar_whitespace(&mut self, whitespace: &str);
   fn visit_button_macro(&mut self, text: &str);
+  fn visit_stem_macro(&mut self, name: &str, text: &str);
   fn visit_menu_macro(&mut self, items: &[&str]);
   fn visit_image_macro(&mut self, target: &str, attrs: &AttrList);
 
@@ -175,15 +187,26 @@ pub trait Backend {
   fn exit_inline_quote(&mut self, kind: QuoteKind, children: &[InlineNode]);
   fn enter_footnote(&mut self, number: u16, id: Option<&str>, content: &[InlineNode]);
   fn exit_footnote(&mut self, number: u16, id: Option<&str>, content: &[InlineNode]);
+  fn visit_footnote_reference(&mut self, number: u16, id: &str);
+  fn visit_missing_footnote_reference(&mut self, id: &str);
   fn enter_text_span(&mut self, attrs: &AttrList, children: &[InlineNode]);
   fn exit_text_span(&mut self, attrs: &AttrList, children: &[InlineNode]);
   fn enter_xref(&mut self, id: &str, target: Option<&[InlineNode]>);
   fn exit_xref(&mut self, id: &str, target: Option<&[InlineNode]>);
   fn visit_missing_xref(&mut self, id: &str);
+  fn enter_interdoc_xref(&mut self, target: InterdocTarget, linktext: Option<&[InlineNode]>);
+  fn exit_interdoc_xref(&mut self, target: InterdocTarget, linktext: Option<&[InlineNode]>);
   fn visit_inline_anchor(&mut self, id: &str);
   fn visit_linebreak(&mut self);
 
   // result
   fn into_result(self) -> Result<Self::Output, Self::Error>;
   fn result(&self) -> Result<&Self::Output, Self::Error>;
+
+  /// takes any output that is complete and will not be revisited, called
+  /// between top-level blocks when streaming to a writer, backends that need
+  /// the whole document before producing output should return `None`
+  fn take_flushable_output(&mut self) -> Option<Self::Output> {
+    None
+  }
 }
@@ -3,14 +3,17 @@ extern crate asciidork_meta as meta;
 
 mod admonition;
 mod backend;
+mod stem;
 
 // TODO: maybe move this into ast?
 pub use admonition::AdmonitionKind;
 
 pub use backend::Backend;
+pub use stem::StemNotation;
 
 pub mod prelude {
   pub use super::AdmonitionKind;
   pub use super::Backend;
-  pub use meta::{AttrValue, DocType};
+  pub use super::StemNotation;
+  pub use meta::{AttrValue, DocType, ReadAttr};
 }
@@ -0,0 +1,36 @@
+use meta::{DocumentMeta, ReadAttr};
+
+#[derive(Copy, Debug, PartialEq, Eq, Clone)]
+pub enum StemNotation {
+  Asciimath,
+  Latexmath,
+}
+
+impl StemNotation {
+  /// resolves a block style or macro name (`stem`, `latexmath`, or
+  /// `asciimath`), where `stem` defers to the `stem` document attribute
+  pub fn resolve(name: &str, doc_meta: &DocumentMeta) -> Self {
+    match name {
+      "latexmath" => StemNotation::Latexmath,
+      "asciimath" => StemNotation::Asciimath,
+      _ => match doc_meta.str("stem") {
+        Some("latexmath" | "latex" | "tex") => StemNotation::Latexmath,
+        _ => StemNotation::Asciimath,
+      },
+    }
+  }
+
+  pub const fn inline_delimiters(&self) -> (&'static str, &'static str) {
+    match self {
+      StemNotation::Asciimath => (r"\$", r"\$"),
+      StemNotation::Latexmath => (r"\(", r"\)"),
+    }
+  }
+
+  pub const fn block_delimiters(&self) -> (&'static str, &'static str) {
+    match self {
+      StemNotation::Asciimath => (r"\$", r"\$"),
+      StemNotation::Latexmath => (r"\[", r"\]"),
+    }
+  }
+}
@@ -63,8 +63,11 @@ fn run(
   parser.apply_job_settings(args.clone().try_into()?);
   parser.set_resolver(Box::new(CliResolver::new(base_dir)));
 
-  let result = parser.parse();
+  let mut result = parser.parse();
   let parse_time = parse_start.elapsed();
+  if let Ok(parse_result) = &mut result {
+    print_diagnostics(&mut stderr, std::mem::take(&mut parse_result.warnings));
+  }
 
   match result {
     Ok(parse_result) => match &args.format {
@@ -0,0 +1,1231 @@
+use std::fmt::Write;
+
+use crate::internal::*;
+
+/// DocBook 5 output, following the markup of the Asciidoctor `docbook5` converter
+#[derive(Debug, Default)]
+pub struct DocBook5 {
+  xml: String,
+  alt_xml: String,
+  doc_meta: DocumentMeta,
+  block_title: Option<String>,
+  newlines: Newlines,
+  default_newlines: Newlines,
+  in_asciidoc_table_cell: bool,
+  callouts: Vec<Callout>,
+  table_col: usize,
+  in_cdata: bool,
+}
+
+impl Backend for DocBook5 {
+  type Output = String;
+  type Error = Infallible;
+
+  fn enter_document(&mut self, document: &Document) {
+    let parent_meta = mem::replace(&mut self.doc_meta, document.meta.clone());
+    if self.in_asciidoc_table_cell {
+      self.doc_meta.inherit_doc_attrs(&parent_meta);
+    }
+    if self.doc_meta.is_true("hardbreaks-option") {
+      self.default_newlines = Newlines::JoinWithBreak;
+      self.newlines = Newlines::JoinWithBreak;
+    }
+    if !self.standalone() {
+      return;
+    }
+    self.push_str(r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+    if self.doc_meta.is_set("toc") {
+      self.push_str("<?asciidoc-toc?>");
+    }
+    if self.doc_meta.is_set("sectnums") {
+      self.push_str("<?asciidoc-numbered?>");
+    }
+    let lang = self.doc_meta.string_or("lang", "en");
+    self.push([
+      "<",
+      self.root_tag(),
+      r#" xmlns="http://docbook.org/ns/docbook" xmlns:xl="http://www.w3.org/1999/xlink" version="5.0""#,
+    ]);
+    if !self.doc_meta.is_true("nolang") {
+      self.push_str(r#" xml:lang=""#);
+      self.push_attr_escaped(&lang);
+      self.push_ch('"');
+    }
+    self.push_ch('>');
+  }
+
+  fn exit_document(&mut self, _document: &Document) {
+    if self.standalone() {
+      self.push(["</", self.root_tag(), ">"]);
+    }
+  }
+
+  fn enter_header(&mut self) {}
+  fn exit_header(&mut self) {}
+  fn enter_content(&mut self) {}
+  fn exit_content(&mut self) {}
+  fn enter_footer(&mut self) {}
+  fn exit_footer(&mut self) {}
+
+  fn visit_document_attribute_decl(&mut self, name: &str, value: &AttrValue) {
+    if name == "hardbreaks-option" {
+      self.default_newlines = if value.is_true() {
+        Newlines::JoinWithBreak
+      } else {
+        Newlines::JoinWithSpace
+      };
+      self.newlines = self.default_newlines;
+    }
+    _ = self.doc_meta.insert_doc_attr(name, value.clone());
+  }
+
+  fn enter_preamble(&mut self, _blocks: &[Block]) {}
+  fn exit_preamble(&mut self, _blocks: &[Block]) {}
+
+  fn enter_document_title(&mut self, _nodes: &[InlineNode]) {
+    if self.standalone() {
+      self.push_str("<info><title>");
+    } else {
+      self.start_buffering();
+    }
+  }
+
+  fn exit_document_title(&mut self, _nodes: &[InlineNode]) {
+    if self.standalone() {
+      self.push_str("</title></info>");
+    } else {
+      self.take_buffer();
+    }
+  }
+
+  // NB: docbook processors generate the toc, see `<?asciidoc-toc?>`
+  fn enter_toc(&mut self, _toc: &TableOfContents) {
+    self.start_buffering();
+  }
+
+  fn exit_toc(&mut self, _toc: &TableOfContents) {
+    self.take_buffer();
+  }
+
+  fn enter_section(&mut self, section: &Section) {
+    self.push(["<", self.section_tag(section)]);
+    if let Some(id) = &section.id {
+      self.push_xml_id(id);
+    }
+    self.push_roles(section.meta.attrs.as_ref());
+    self.push_ch('>');
+  }
+
+  fn exit_section(&mut self, section: &Section) {
+    self.push(["</", self.section_tag(section), ">"]);
+  }
+
+  fn enter_section_heading(&mut self, _section: &Section) {
+    self.push_str("<title>");
+  }
+
+  fn exit_section_heading(&mut self, _section: &Section) {
+    self.push_str("</title>");
+  }
+
+  fn enter_paragraph_block(&mut self, block: &Block) {
+    if block.meta.title.is_some() {
+      self.open_block("formalpara", block);
+      self.push_block_title();
+      self.push_str("<para>");
+    } else {
+      self.open_block("simpara", block);
+    }
+  }
+
+  fn exit_paragraph_block(&mut self, block: &Block) {
+    if block.meta.title.is_some() {
+      self.push_str("</para></formalpara>");
+    } else {
+      self.push_str("</simpara>");
+    }
+  }
+
+  fn enter_sidebar_block(&mut self, block: &Block, content: &BlockContent) {
+    self.open_block("sidebar", block);
+    self.push_block_title();
+    self.open_simple_content(content);
+  }
+
+  fn exit_sidebar_block(&mut self, _block: &Block, content: &BlockContent) {
+    self.close_simple_content(content);
+    self.push_str("</sidebar>");
+  }
+
+  fn enter_open_block(&mut self, block: &Block, _content: &BlockContent) {
+    if block.meta.title.is_some() {
+      self.open_block("formalpara", block);
+      self.push_block_title();
+      self.push_str("<para>");
+    } else if has_id_or_roles(block) {
+      self.open_block("para", block);
+    }
+  }
+
+  fn exit_open_block(&mut self, block: &Block, _content: &BlockContent) {
+    if block.meta.title.is_some() {
+      self.push_str("</para></formalpara>");
+    } else if has_id_or_roles(block) {
+      self.push_str("</para>");
+    }
+  }
+
+  fn enter_example_block(&mut self, block: &Block, content: &BlockContent) {
+    if block.meta.title.is_some() {
+      self.open_block("example", block);
+      self.push_block_title();
+    } else {
+      self.open_block("informalexample", block);
+    }
+    self.open_simple_content(content);
+  }
+
+  fn exit_example_block(&mut self, block: &Block, content: &BlockContent) {
+    self.close_simple_content(content);
+    if block.meta.title.is_some() {
+      self.push_str("</example>");
+    } else {
+      self.push_str("</informalexample>");
+    }
+  }
+
+  fn enter_quote_block(&mut self, block: &Block, content: &BlockContent) {
+    self.open_block("blockquote", block);
+    self.push_block_title();
+    self.push_attribution(block);
+    self.open_simple_content(content);
+  }
+
+  fn exit_quote_block(&mut self, _block: &Block, content: &BlockContent) {
+    self.close_simple_content(content);
+    self.push_str("</blockquote>");
+  }
+
+  fn enter_verse_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.open_block("blockquote", block);
+    self.push_block_title();
+    self.push_attribution(block);
+    self.push_str("<literallayout>");
+  }
+
+  fn exit_verse_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.push_str("</literallayout></blockquote>");
+  }
+
+  fn enter_listing_block(&mut self, block: &Block, _content: &BlockContent) {
+    if block.meta.title.is_some() {
+      self.open_block("formalpara", block);
+      self.push_block_title();
+      self.push_str("<para>");
+    }
+    match self.source_lang(block) {
+      Some(lang) => {
+        self.push_str("<programlisting");
+        if block.meta.title.is_none() {
+          self.push_common_attrs(block.meta.attrs.as_ref());
+        }
+        self.push_str(r#" language=""#);
+        self.push_attr_escaped(&lang);
+        self.push_str(r#"" linenumbering="unnumbered">"#);
+      }
+      None if block.meta.title.is_none() => self.open_block("screen", block),
+      None => self.push_str("<screen>"),
+    }
+  }
+
+  fn exit_listing_block(&mut self, block: &Block, _content: &BlockContent) {
+    if self.source_lang(block).is_some() {
+      self.push_str("</programlisting>");
+    } else {
+      self.push_str("</screen>");
+    }
+    if block.meta.title.is_some() {
+      self.push_str("</para></formalpara>");
+    }
+  }
+
+  fn enter_literal_block(&mut self, block: &Block, _content: &BlockContent) {
+    if block.meta.title.is_some() {
+      self.open_block("formalpara", block);
+      self.push_block_title();
+      self.push_str(r#"<para><literallayout class="monospaced">"#);
+    } else {
+      self.push_str("<literallayout");
+      self.push_common_attrs(block.meta.attrs.as_ref());
+      self.push_str(r#" class="monospaced">"#);
+    }
+  }
+
+  fn exit_literal_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.push_str("</literallayout>");
+    if block.meta.title.is_some() {
+      self.push_str("</para></formalpara>");
+    }
+  }
+
+  fn enter_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+
+  fn enter_stem_block(&mut self, block: &Block, _content: &BlockContent) {
+    if block.meta.title.is_some() {
+      self.open_block("equation", block);
+      self.push_block_title();
+    } else {
+      self.open_block("informalequation", block);
+    }
+    self.push_str("<mathphrase><![CDATA[");
+    self.in_cdata = true;
+  }
+
+  fn exit_stem_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.in_cdata = false;
+    self.push_str("]]></mathphrase>");
+    if block.meta.title.is_some() {
+      self.push_str("</equation>");
+    } else {
+      self.push_str("</informalequation>");
+    }
+  }
+
+  fn enter_image_block(&mut self, target: &str, attrs: &AttrList, block: &Block) {
+    self.open_figure(block);
+    self.push_str("<mediaobject><imageobject>");
+    self.push_imagedata(target, attrs);
+    self.push_str("</imageobject><textobject><phrase>");
+    self.push_image_alt(target, attrs);
+    self.push_str("</phrase></textobject></mediaobject>");
+  }
+
+  fn exit_image_block(&mut self, block: &Block) {
+    self.close_figure(block);
+  }
+
+  fn enter_audio_block(&mut self, target: &str, _attrs: &AttrList, block: &Block) {
+    self.open_figure(block);
+    self.push_str(r#"<mediaobject><audioobject><audiodata fileref=""#);
+    self.push_media_path(target);
+    self.push_str(r#""/></audioobject></mediaobject>"#);
+  }
+
+  fn exit_audio_block(&mut self, block: &Block) {
+    self.close_figure(block);
+  }
+
+  fn enter_video_block(&mut self, target: &str, attrs: &AttrList, block: &Block) {
+    self.open_figure(block);
+    self.push_str(r#"<mediaobject><videoobject><videodata fileref=""#);
+    self.push_media_path(target);
+    self.push_ch('"');
+    self.push_dimension_attrs(attrs);
+    self.push_str("/></videoobject></mediaobject>");
+  }
+
+  fn exit_video_block(&mut self, block: &Block) {
+    self.close_figure(block);
+  }
+
+  fn enter_admonition_block(&mut self, kind: AdmonitionKind, block: &Block) {
+    self.open_block(kind.lowercase_str(), block);
+    self.push_block_title();
+    self.open_simple_content(&block.content);
+  }
+
+  fn exit_admonition_block(&mut self, kind: AdmonitionKind, block: &Block) {
+    self.close_simple_content(&block.content);
+    self.push(["</", kind.lowercase_str(), ">"]);
+  }
+
+  fn enter_quoted_paragraph(&mut self, block: &Block, attr: &str, cite: Option<&str>) {
+    self.open_block("blockquote", block);
+    self.push_block_title();
+    self.push_str("<attribution>");
+    self.push_attr_escaped(attr);
+    self.push_citetitle(cite);
+    self.push_str("</attribution><simpara>");
+  }
+
+  fn exit_quoted_paragraph(&mut self, _block: &Block, _attr: &str, _cite: Option<&str>) {
+    self.push_str("</simpara></blockquote>");
+  }
+
+  fn enter_discrete_heading(&mut self, level: u8, id: Option<&str>, _block: &Block) {
+    self.push_str("<bridgehead");
+    if let Some(id) = id {
+      self.push_xml_id(id);
+    }
+    self.push([r#" renderas="sect"#, &level.to_string(), "\">"]);
+  }
+
+  fn exit_discrete_heading(&mut self, _level: u8, _id: Option<&str>, _block: &Block) {
+    self.push_str("</bridgehead>");
+  }
+
+  fn enter_unordered_list(&mut self, block: &Block, _items: &[ListItem], _depth: u8) {
+    self.open_block("itemizedlist", block);
+    self.push_block_title();
+  }
+
+  fn exit_unordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.push_str("</itemizedlist>");
+  }
+
+  fn enter_ordered_list(&mut self, block: &Block, items: &[ListItem], depth: u8) {
+    let attrs = block.meta.attrs.as_ref();
+    let numeration = attrs
+      .and_then(|attrs| attrs.ordered_list_custom_number_style())
+      .unwrap_or_else(|| numeration_from_depth(depth));
+    self.push_str("<orderedlist");
+    self.push_common_attrs(attrs);
+    self.push([r#" numeration=""#, numeration, "\""]);
+    if let Some(start) = attrs.and_then(|attrs| attrs.named("start")) {
+      self.push_str(r#" startingnumber=""#);
+      self.push_attr_escaped(start);
+      self.push_ch('"');
+    } else if let Some(ListMarker::Digits(n)) = items.first().map(|item| item.marker) {
+      if n != 1 {
+        self.push([r#" startingnumber=""#, &n.to_string(), "\""]);
+      }
+    }
+    self.push_ch('>');
+    self.push_block_title();
+  }
+
+  fn exit_ordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.push_str("</orderedlist>");
+  }
+
+  fn enter_callout_list(&mut self, block: &Block, _items: &[ListItem], _depth: u8) {
+    self.open_block("calloutlist", block);
+    self.push_block_title();
+  }
+
+  fn exit_callout_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.push_str("</calloutlist>");
+  }
+
+  fn enter_description_list(&mut self, block: &Block, _items: &[ListItem], _depth: u8) {
+    self.open_block("variablelist", block);
+    self.push_block_title();
+  }
+
+  fn exit_description_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.push_str("</variablelist>");
+  }
+
+  fn enter_description_list_term(&mut self, _item: &ListItem) {
+    self.push_str("<varlistentry><term>");
+  }
+
+  fn exit_description_list_term(&mut self, _item: &ListItem) {
+    self.push_str("</term>");
+  }
+
+  fn enter_description_list_description(&mut self, _blocks: &[Block], _item: &ListItem) {
+    self.push_str("<listitem>");
+  }
+
+  fn exit_description_list_description(&mut self, _blocks: &[Block], _item: &ListItem) {
+    self.push_str("</listitem></varlistentry>");
+  }
+
+  fn enter_list_item_principal(&mut self, item: &ListItem, variant: ListVariant) {
+    if variant != ListVariant::Callout {
+      self.push_str("<listitem><simpara>");
+      return;
+    }
+    // NB: refs the callouts w/ this number in the most recent listing
+    let number = item.marker.callout_num();
+    let list_idx = self.callouts.last().map(|callout| callout.list_idx);
+    let arearefs = self
+      .callouts
+      .iter()
+      .filter(|callout| Some(callout.list_idx) == list_idx && Some(callout.number) == number)
+      .map(callout_id)
+      .collect::<Vec<_>>()
+      .join(" ");
+    self.push([r#"<callout arearefs=""#, &arearefs, r#""><para>"#]);
+  }
+
+  fn exit_list_item_principal(&mut self, _item: &ListItem, variant: ListVariant) {
+    if variant == ListVariant::Callout {
+      self.push_str("</para>");
+    } else {
+      self.push_str("</simpara>");
+    }
+  }
+
+  fn enter_list_item_blocks(&mut self, _blocks: &[Block], _item: &ListItem, _: ListVariant) {}
+
+  fn exit_list_item_blocks(&mut self, _blocks: &[Block], _item: &ListItem, variant: ListVariant) {
+    if variant == ListVariant::Callout {
+      self.push_str("</callout>");
+    } else {
+      self.push_str("</listitem>");
+    }
+  }
+
+  fn enter_table(&mut self, table: &Table, block: &Block) {
+    let tag = if block.meta.title.is_some() { "table" } else { "informaltable" };
+    self.push(["<", tag]);
+    self.push_common_attrs(block.meta.attrs.as_ref());
+    let frame = match block.named_attr("frame").unwrap_or("all") {
+      "ends" => "topbot",
+      frame => frame,
+    };
+    let grid = block.named_attr("grid").unwrap_or("all");
+    let rowsep = if matches!(grid, "none" | "cols") { "0" } else { "1" };
+    let colsep = if matches!(grid, "none" | "rows") { "0" } else { "1" };
+    self.push_str(r#" frame=""#);
+    self.push_attr_escaped(frame);
+    self.push([r#"" rowsep=""#, rowsep]);
+    self.push([r#"" colsep=""#, colsep, "\">"]);
+    self.push_block_title();
+    self.push([
+      r#"<tgroup cols=""#,
+      &table.col_widths.len().to_string(),
+      "\">",
+    ]);
+    for (idx, width) in table.col_widths.distribute().enumerate() {
+      write!(self.xml, r#"<colspec colname="col_{}""#, idx + 1).unwrap();
+      if let DistributedColWidth::Percentage(width) = width {
+        let width = format!("{:.4}", width);
+        let width = width.trim_end_matches('0').trim_end_matches('.');
+        self.push([r#" colwidth=""#, width, "*\""]);
+      }
+      self.push_str("/>");
+    }
+  }
+
+  fn exit_table(&mut self, _table: &Table, block: &Block) {
+    if block.meta.title.is_some() {
+      self.push_str("</tgroup></table>");
+    } else {
+      self.push_str("</tgroup></informaltable>");
+    }
+  }
+
+  fn enter_table_section(&mut self, section: TableSection) {
+    match section {
+      TableSection::Header => self.push_str("<thead>"),
+      TableSection::Body => self.push_str("<tbody>"),
+      TableSection::Footer => self.push_str("<tfoot>"),
+    }
+  }
+
+  fn exit_table_section(&mut self, section: TableSection) {
+    match section {
+      TableSection::Header => self.push_str("</thead>"),
+      TableSection::Body => self.push_str("</tbody>"),
+      TableSection::Footer => self.push_str("</tfoot>"),
+    }
+  }
+
+  fn enter_table_row(&mut self, _row: &Row, _section: TableSection) {
+    self.table_col = 0;
+    self.push_str("<row>");
+  }
+
+  fn exit_table_row(&mut self, _row: &Row, _section: TableSection) {
+    self.push_str("</row>");
+  }
+
+  fn enter_table_cell(&mut self, cell: &Cell, _section: TableSection) {
+    let first_col = self.table_col + 1;
+    self.table_col += cell.col_span as usize;
+    let align = match cell.h_align {
+      HorizontalAlignment::Left => "left",
+      HorizontalAlignment::Center => "center",
+      HorizontalAlignment::Right => "right",
+    };
+    let valign = match cell.v_align {
+      VerticalAlignment::Top => "top",
+      VerticalAlignment::Middle => "middle",
+      VerticalAlignment::Bottom => "bottom",
+    };
+    self.push([r#"<entry align=""#, align, r#"" valign=""#, valign, "\""]);
+    if cell.col_span > 1 {
+      let (start, end) = (first_col, self.table_col);
+      write!(self.xml, r#" namest="col_{start}" nameend="col_{end}""#).unwrap();
+    }
+    if cell.row_span > 1 {
+      write!(self.xml, r#" morerows="{}""#, cell.row_span - 1).unwrap();
+    }
+    self.push_ch('>');
+    if let CellContent::Literal(_) = cell.content {
+      self.push_str(r#"<literallayout class="monospaced">"#);
+      self.newlines = Newlines::Preserve;
+    }
+  }
+
+  fn exit_table_cell(&mut self, cell: &Cell, _section: TableSection) {
+    if let CellContent::Literal(_) = cell.content {
+      self.push_str("</literallayout>");
+      self.newlines = self.default_newlines;
+    }
+    self.push_str("</entry>");
+  }
+
+  fn enter_cell_paragraph(&mut self, cell: &Cell, section: TableSection) {
+    if section == TableSection::Header {
+      return;
+    }
+    self.push_str("<simpara>");
+    match cell.content {
+      CellContent::Emphasis(_) => self.push_str("<emphasis>"),
+      CellContent::Header(_) | CellContent::Strong(_) => {
+        self.push_str(r#"<emphasis role="strong">"#)
+      }
+      CellContent::Monospace(_) => self.push_str("<literal>"),
+      _ => {}
+    }
+  }
+
+  fn exit_cell_paragraph(&mut self, cell: &Cell, section: TableSection) {
+    if section == TableSection::Header {
+      return;
+    }
+    match cell.content {
+      CellContent::Emphasis(_) | CellContent::Header(_) | CellContent::Strong(_) => {
+        self.push_str("</emphasis>")
+      }
+      CellContent::Monospace(_) => self.push_str("</literal>"),
+      _ => {}
+    }
+    self.push_str("</simpara>");
+  }
+
+  fn asciidoc_table_cell_backend(&mut self) -> Self {
+    Self {
+      in_asciidoc_table_cell: true,
+      doc_meta: self.doc_meta.clone(),
+      ..Self::default()
+    }
+  }
+
+  fn visit_asciidoc_table_cell_result(&mut self, result: Result<Self::Output, Self::Error>) {
+    self.xml.push_str(&result.unwrap());
+  }
+
+  fn enter_block_title(&mut self, _title: &[InlineNode], _block: &Block) {
+    self.start_buffering();
+  }
+
+  fn exit_block_title(&mut self, _title: &[InlineNode], _block: &Block) {
+    self.block_title = Some(self.take_buffer());
+  }
+
+  fn enter_simple_block_content(&mut self, _children: &[InlineNode], block: &Block) {
+    if matches!(
+      block.context,
+      BlockContext::Verse
+        | BlockContext::Listing
+        | BlockContext::Literal
+        | BlockContext::Passthrough
+    ) {
+      self.newlines = Newlines::Preserve;
+    } else if block.has_attr_option("hardbreaks") {
+      self.newlines = Newlines::JoinWithBreak;
+    }
+  }
+
+  fn exit_simple_block_content(&mut self, _children: &[InlineNode], _block: &Block) {
+    self.newlines = self.default_newlines;
+  }
+
+  fn enter_compound_block_content(&mut self, _children: &[Block], _block: &Block) {}
+  fn exit_compound_block_content(&mut self, _children: &[Block], _block: &Block) {}
+
+  fn visit_thematic_break(&mut self, _block: &Block) {
+    self.push_str("<simpara><?asciidoc-hr?></simpara>");
+  }
+
+  fn visit_page_break(&mut self, _block: &Block) {
+    self.push_str("<simpara><?asciidoc-pagebreak?></simpara>");
+  }
+
+  fn visit_inline_text(&mut self, text: &str) {
+    if self.in_cdata {
+      self.push_cdata(text);
+    } else {
+      self.push_str(text);
+    }
+  }
+
+  fn visit_inline_lit_mono(&mut self, text: &str) {
+    self.push(["<literal>", text, "</literal>"]);
+  }
+
+  fn visit_joining_newline(&mut self) {
+    match self.newlines {
+      Newlines::JoinWithSpace => self.push_ch(' '),
+      Newlines::JoinWithBreak => self.push_str("<?asciidoc-br?>\n"),
+      Newlines::Preserve => self.push_ch('\n'),
+    }
+  }
+
+  fn visit_curly_quote(&mut self, kind: CurlyKind) {
+    match kind {
+      CurlyKind::LeftDouble => self.push_str("&#8221;"),
+      CurlyKind::RightDouble => self.push_str("&#8220;"),
+      CurlyKind::LeftSingle => self.push_str("&#8217;"),
+      CurlyKind::RightSingle => self.push_str("&#8216;"),
+      CurlyKind::LegacyImplicitApostrophe => self.push_str("&#8217;"),
+    }
+  }
+
+  fn visit_multichar_whitespace(&mut self, whitespace: &str) {
+    if self.newlines == Newlines::Preserve {
+      self.push_str(whitespace);
+    } else {
+      self.push_ch(' ');
+    }
+  }
+
+  fn visit_button_macro(&mut self, text: &str) {
+    self.push_str("<guibutton>");
+    self.push_attr_escaped(text);
+    self.push_str("</guibutton>");
+  }
+
+  fn visit_stem_macro(&mut self, _name: &str, text: &str) {
+    self.push_str("<inlineequation><mathphrase><![CDATA[");
+    self.push_cdata(text);
+    self.push_str("]]></mathphrase></inlineequation>");
+  }
+
+  fn visit_menu_macro(&mut self, items: &[&str]) {
+    let Some((menu, rest)) = items.split_first() else {
+      return;
+    };
+    if rest.is_empty() {
+      self.push_str("<guimenu>");
+      self.push_attr_escaped(menu);
+      self.push_str("</guimenu>");
+      return;
+    }
+    self.push_str("<menuchoice><guimenu>");
+    self.push_attr_escaped(menu);
+    self.push_str("</guimenu>");
+    let last_idx = rest.len() - 1;
+    for (idx, item) in rest.iter().enumerate() {
+      let tag = if idx == last_idx { "guimenuitem" } else { "guisubmenu" };
+      self.push([" <", tag, ">"]);
+      self.push_attr_escaped(item);
+      self.push(["</", tag, ">"]);
+    }
+    self.push_str("</menuchoice>");
+  }
+
+  fn visit_image_macro(&mut self, target: &str, attrs: &AttrList) {
+    self.push_str("<inlinemediaobject><imageobject>");
+    self.push_imagedata(target, attrs);
+    self.push_str("</imageobject><textobject><phrase>");
+    self.push_image_alt(target, attrs);
+    self.push_str("</phrase></textobject></inlinemediaobject>");
+  }
+
+  fn visit_keyboard_macro(&mut self, keys: &[&str]) {
+    if keys.len() > 1 {
+      self.push_str("<keycombo>");
+    }
+    for key in keys {
+      self.push_str("<keycap>");
+      self.push_attr_escaped(key);
+      self.push_str("</keycap>");
+    }
+    if keys.len() > 1 {
+      self.push_str("</keycombo>");
+    }
+  }
+
+  fn enter_link_macro(
+    &mut self,
+    target: &str,
+    _attrs: Option<&AttrList>,
+    scheme: Option<UrlScheme>,
+    _has_link_text: bool,
+    _blank_window_shorthand: bool,
+  ) {
+    self.push_str(r#"<link xl:href=""#);
+    if matches!(scheme, Some(UrlScheme::Mailto)) {
+      self.push_str("mailto:");
+    }
+    self.push_attr_escaped(target);
+    self.push_str("\">");
+  }
+
+  fn exit_link_macro(
+    &mut self,
+    target: &str,
+    _attrs: Option<&AttrList>,
+    _scheme: Option<UrlScheme>,
+    has_link_text: bool,
+  ) {
+    if !has_link_text {
+      self.push_attr_escaped(target);
+    }
+    self.push_str("</link>");
+  }
+
+  fn visit_callout(&mut self, callout: Callout) {
+    if !self.xml.ends_with(' ') {
+      self.push_ch(' ');
+    }
+    self.push([r#"<co xml:id=""#, &callout_id(&callout), r#""/>"#]);
+    self.callouts.push(callout);
+  }
+
+  fn visit_callout_tuck(&mut self, comment: &str) {
+    self.push_str(comment);
+  }
+
+  fn enter_inline_italic(&mut self, _children: &[InlineNode]) {
+    self.push_str("<emphasis>");
+  }
+
+  fn exit_inline_italic(&mut self, _children: &[InlineNode]) {
+    self.push_str("</emphasis>");
+  }
+
+  fn enter_inline_mono(&mut self, _children: &[InlineNode]) {
+    self.push_str("<literal>");
+  }
+
+  fn exit_inline_mono(&mut self, _children: &[InlineNode]) {
+    self.push_str("</literal>");
+  }
+
+  fn enter_inline_bold(&mut self, _children: &[InlineNode]) {
+    self.push_str(r#"<emphasis role="strong">"#);
+  }
+
+  fn exit_inline_bold(&mut self, _children: &[InlineNode]) {
+    self.push_str("</emphasis>");
+  }
+
+  fn visit_inline_specialchar(&mut self, char: &SpecialCharKind) {
+    // NB: CDATA content is taken literally, so must not be escaped
+    if self.in_cdata {
+      return self.push_cdata(match char {
+        SpecialCharKind::Ampersand => "&",
+        SpecialCharKind::LessThan => "<",
+        SpecialCharKind::GreaterThan => ">",
+      });
+    }
+    match char {
+      SpecialCharKind::Ampersand => self.push_str("&amp;"),
+      SpecialCharKind::LessThan => self.push_str("&lt;"),
+      SpecialCharKind::GreaterThan => self.push_str("&gt;"),
+    }
+  }
+
+  fn enter_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+
+  fn enter_inline_highlight(&mut self, _children: &[InlineNode]) {
+    self.push_str(r#"<emphasis role="marked">"#);
+  }
+
+  fn exit_inline_highlight(&mut self, _children: &[InlineNode]) {
+    self.push_str("</emphasis>");
+  }
+
+  fn enter_inline_subscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("<subscript>");
+  }
+
+  fn exit_inline_subscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("</subscript>");
+  }
+
+  fn enter_inline_superscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("<superscript>");
+  }
+
+  fn exit_inline_superscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("</superscript>");
+  }
+
+  fn enter_inline_quote(&mut self, kind: QuoteKind, _children: &[InlineNode]) {
+    match kind {
+      QuoteKind::Double => self.push_str("&#8220;"),
+      QuoteKind::Single => self.push_str("&#8216;"),
+    }
+  }
+
+  fn exit_inline_quote(&mut self, kind: QuoteKind, _children: &[InlineNode]) {
+    match kind {
+      QuoteKind::Double => self.push_str("&#8221;"),
+      QuoteKind::Single => self.push_str("&#8217;"),
+    }
+  }
+
+  fn enter_footnote(&mut self, _number: u16, id: Option<&str>, _content: &[InlineNode]) {
+    self.push_str("<footnote");
+    if let Some(id) = id {
+      self.push_str(r#" xml:id="_footnote_"#);
+      self.push_attr_escaped(id);
+      self.push_ch('"');
+    }
+    self.push_str("><simpara>");
+  }
+
+  fn exit_footnote(&mut self, _number: u16, _id: Option<&str>, _content: &[InlineNode]) {
+    self.push_str("</simpara></footnote>");
+  }
+
+  fn visit_footnote_reference(&mut self, _number: u16, id: &str) {
+    self.push_str(r#"<footnoteref linkend="_footnote_"#);
+    self.push_attr_escaped(id);
+    self.push_str(r#""/>"#);
+  }
+
+  fn visit_missing_footnote_reference(&mut self, id: &str) {
+    self.push_ch('[');
+    self.push_attr_escaped(id);
+    self.push_ch(']');
+  }
+
+  fn enter_text_span(&mut self, attrs: &AttrList, _children: &[InlineNode]) {
+    self.push_str("<phrase");
+    self.push_common_attrs(Some(attrs));
+    self.push_ch('>');
+  }
+
+  fn exit_text_span(&mut self, _attrs: &AttrList, _children: &[InlineNode]) {
+    self.push_str("</phrase>");
+  }
+
+  fn enter_xref(&mut self, id: &str, _target: Option<&[InlineNode]>) {
+    self.push_str(r#"<link linkend=""#);
+    self.push_attr_escaped(id);
+    self.push_str("\">");
+  }
+
+  fn exit_xref(&mut self, _id: &str, _target: Option<&[InlineNode]>) {
+    self.push_str("</link>");
+  }
+
+  fn visit_missing_xref(&mut self, id: &str) {
+    self.push_ch('[');
+    self.push_attr_escaped(id);
+    self.push_ch(']');
+  }
+
+  fn enter_interdoc_xref(&mut self, target: InterdocTarget, _linktext: Option<&[InlineNode]>) {
+    let href = target.href(&self.doc_meta, ".xml");
+    self.push_str(r#"<link xl:href=""#);
+    self.push_attr_escaped(&href);
+    self.push_str("\">");
+  }
+
+  fn exit_interdoc_xref(&mut self, target: InterdocTarget, linktext: Option<&[InlineNode]>) {
+    if linktext.is_none() {
+      let path = target.out_path(&self.doc_meta, ".xml");
+      self.push_str(&path);
+    }
+    self.push_str("</link>");
+  }
+
+  fn visit_inline_anchor(&mut self, id: &str) {
+    self.push_str("<anchor");
+    self.push_xml_id(id);
+    self.push_str(r#" xreflabel="["#);
+    self.push_attr_escaped(id);
+    self.push_str(r#"]"/>"#);
+  }
+
+  fn visit_linebreak(&mut self) {
+    self.push_str("<?asciidoc-br?>\n");
+  }
+
+  fn into_result(self) -> Result<Self::Output, Self::Error> {
+    Ok(self.xml)
+  }
+
+  fn result(&self) -> Result<&Self::Output, Self::Error> {
+    Ok(&self.xml)
+  }
+}
+
+impl DocBook5 {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn into_string(self) -> String {
+    self.xml
+  }
+
+  fn push_str(&mut self, s: &str) {
+    self.xml.push_str(s);
+  }
+
+  fn push_ch(&mut self, c: char) {
+    self.xml.push(c);
+  }
+
+  fn push<const N: usize>(&mut self, strs: [&str; N]) {
+    for s in strs {
+      self.push_str(s);
+    }
+  }
+
+  fn push_attr_escaped(&mut self, s: &str) {
+    for c in s.chars() {
+      match c {
+        '&' => self.push_str("&amp;"),
+        '<' => self.push_str("&lt;"),
+        '>' => self.push_str("&gt;"),
+        '"' => self.push_str("&quot;"),
+        _ => self.push_ch(c),
+      }
+    }
+  }
+
+  fn push_xml_id(&mut self, id: &str) {
+    self.push_str(r#" xml:id=""#);
+    self.push_attr_escaped(id);
+    self.push_ch('"');
+  }
+
+  /// pushes text into an open CDATA section, splitting any `]]>` which
+  /// would otherwise end the section early
+  fn push_cdata(&mut self, s: &str) {
+    for c in s.chars() {
+      if c == '>' && self.xml.ends_with("]]") {
+        self.push_str("]]><![CDATA[");
+      }
+      self.push_ch(c);
+    }
+  }
+
+  const fn start_buffering(&mut self) {
+    mem::swap(&mut self.xml, &mut self.alt_xml);
+  }
+
+  fn take_buffer(&mut self) -> String {
+    mem::swap(&mut self.alt_xml, &mut self.xml);
+    mem::take(&mut self.alt_xml)
+  }
+
+  fn standalone(&self) -> bool {
+    self.doc_meta.get_doctype() != DocType::Inline
+      && !self.in_asciidoc_table_cell
+      && !self.doc_meta.embedded
+  }
+
+  fn root_tag(&self) -> &'static str {
+    if self.doc_meta.get_doctype() == DocType::Book {
+      "book"
+    } else {
+      "article"
+    }
+  }
+
+  fn section_tag(&self, section: &Section) -> &'static str {
+    let style = section
+      .meta
+      .attrs
+      .as_ref()
+      .and_then(|attrs| attrs.str_positional_at(0));
+    match style {
+      Some("appendix") => "appendix",
+      Some("glossary") => "glossary",
+      Some("bibliography") => "bibliography",
+      Some("index") => "index",
+      Some("colophon") => "colophon",
+      Some("dedication") => "dedication",
+      Some("preface") => "preface",
+      _ if section.level == 0 => "part",
+      _ if section.level == 1 && self.doc_meta.get_doctype() == DocType::Book => "chapter",
+      _ => "section",
+    }
+  }
+
+  fn open_block(&mut self, tag: &str, block: &Block) {
+    self.push(["<", tag]);
+    self.push_common_attrs(block.meta.attrs.as_ref());
+    self.push_ch('>');
+  }
+
+  fn push_common_attrs(&mut self, attrs: Option<&AttrList>) {
+    let Some(attrs) = attrs else {
+      return;
+    };
+    if let Some(id) = &attrs.id {
+      self.push_xml_id(&id.src);
+    }
+    self.push_roles(Some(attrs));
+  }
+
+  fn push_roles(&mut self, attrs: Option<&AttrList>) {
+    let Some(attrs) = attrs.filter(|attrs| !attrs.roles.is_empty()) else {
+      return;
+    };
+    self.push_str(r#" role=""#);
+    for (idx, role) in attrs.roles.iter().enumerate() {
+      if idx > 0 {
+        self.push_ch(' ');
+      }
+      self.push_attr_escaped(role);
+    }
+    self.push_ch('"');
+  }
+
+  fn push_block_title(&mut self) {
+    if let Some(title) = self.block_title.take() {
+      self.push(["<title>", &title, "</title>"]);
+    }
+  }
+
+  fn open_simple_content(&mut self, content: &BlockContent) {
+    if let BlockContent::Simple(_) = content {
+      self.push_str("<simpara>");
+    }
+  }
+
+  fn close_simple_content(&mut self, content: &BlockContent) {
+    if let BlockContent::Simple(_) = content {
+      self.push_str("</simpara>");
+    }
+  }
+
+  fn push_attribution(&mut self, block: &Block) {
+    let Some(attrs) = &block.meta.attrs else {
+      return;
+    };
+    // NB: plain text, so values w/ special chars aren't dropped
+    let positional_text = |idx: usize| match attrs.positional.get(idx) {
+      Some(Some(nodes)) => Some(nodes.plain_text().concat()),
+      _ => None,
+    };
+    let (attribution, cite) = (positional_text(1), positional_text(2));
+    if attribution.is_none() && cite.is_none() {
+      return;
+    }
+    self.push_str("<attribution>");
+    if let Some(attribution) = &attribution {
+      self.push_attr_escaped(attribution);
+    }
+    self.push_citetitle(cite.as_deref());
+    self.push_str("</attribution>");
+  }
+
+  fn push_citetitle(&mut self, cite: Option<&str>) {
+    if let Some(cite) = cite {
+      self.push_str("<citetitle>");
+      self.push_attr_escaped(cite);
+      self.push_str("</citetitle>");
+    }
+  }
+
+  fn open_figure(&mut self, block: &Block) {
+    if block.meta.title.is_some() {
+      self.open_block("figure", block);
+      self.push_block_title();
+    } else {
+      self.open_block("informalfigure", block);
+    }
+  }
+
+  fn close_figure(&mut self, block: &Block) {
+    if block.meta.title.is_some() {
+      self.push_str("</figure>");
+    } else {
+      self.push_str("</informalfigure>");
+    }
+  }
+
+  fn push_imagedata(&mut self, target: &str, attrs: &AttrList) {
+    self.push_str(r#"<imagedata fileref=""#);
+    self.push_media_path(target);
+    self.push_ch('"');
+    self.push_dimension_attrs(attrs);
+    self.push_str("/>");
+  }
+
+  fn push_dimension_attrs(&mut self, attrs: &AttrList) {
+    let width = attrs.named("width").or_else(|| attrs.str_positional_at(1));
+    let height = attrs.named("height").or_else(|| attrs.str_positional_at(2));
+    for (name, value) in [("contentwidth", width), ("contentdepth", height)] {
+      if let Some(value) = value {
+        self.push([" ", name, "=\""]);
+        self.push_attr_escaped(value);
+        self.push_ch('"');
+      }
+    }
+  }
+
+  fn push_image_alt(&mut self, target: &str, attrs: &AttrList) {
+    if let Some(alt) = attrs.named("alt").or_else(|| attrs.str_positional_at(0)) {
+      self.push_attr_escaped(alt);
+    } else if let Some(Some(nodes)) = attrs.positional.first() {
+      for s in nodes.plain_text() {
+        self.push_attr_escaped(s);
+      }
+    } else {
+      let basename = target.rsplit(['/', '\\']).next().unwrap_or(target);
+      let stem = basename.split('.').next().unwrap_or(basename);
+      self.push_attr_escaped(&stem.replace(['-', '_'], " "));
+    }
+  }
+
+  /// resolves relative targets against `imagesdir`
+  fn push_media_path(&mut self, target: &str) {
+    let is_absolute = Path::is_absolute_or_uri(target);
+    match self.doc_meta.str("imagesdir").filter(|_| !is_absolute) {
+      Some(imagesdir) => {
+        let path = Path::new(imagesdir).join(target).to_string();
+        self.push_attr_escaped(&path);
+      }
+      None => self.push_attr_escaped(target),
+    }
+  }
+
+  fn source_lang(&self, block: &Block) -> Option<String> {
+    let attrs = block.meta.attrs.as_ref();
+    match attrs.map_or((None, None), |a| {
+      (a.str_positional_at(0), a.str_positional_at(1))
+    }) {
+      (None | Some("source"), Some(lang)) => Some(lang.to_string()),
+      (Some("source"), None) => self.doc_meta.string("source-language"),
+      _ => None,
+    }
+  }
+}
+
+fn has_id_or_roles(block: &Block) -> bool {
+  block
+    .meta
+    .attrs
+    .as_ref()
+    .is_some_and(|attrs| attrs.id.is_some() || !attrs.roles.is_empty())
+}
+
+fn callout_id(callout: &Callout) -> String {
+  format!("CO{}-{}", callout.list_idx + 1, callout.callout_idx + 1)
+}
+
+const fn numeration_from_depth(depth: u8) -> &'static str {
+  match depth {
+    1 => "arabic",
+    2 => "loweralpha",
+    3 => "lowerroman",
+    4 => "upperalpha",
+    _ => "upperroman",
+  }
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Newlines {
+  #[default]
+  JoinWithSpace,
+  JoinWithBreak,
+  Preserve,
+}
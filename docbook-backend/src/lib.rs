@@ -0,0 +1,23 @@
+use std::error::Error;
+
+extern crate asciidork_ast as ast;
+extern crate asciidork_backend as backend;
+extern crate asciidork_eval as eval;
+extern crate asciidork_meta as meta;
+
+mod docbook5;
+
+pub use docbook5::DocBook5;
+
+pub fn convert(document: ast::Document) -> Result<String, Box<dyn Error>> {
+  Ok(eval::eval(&document, DocBook5::new())?)
+}
+
+mod internal {
+  pub use std::convert::Infallible;
+  pub use std::mem;
+
+  pub use ast::prelude::*;
+  pub use backend::prelude::*;
+  pub use meta::*;
+}
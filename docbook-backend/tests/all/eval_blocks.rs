@@ -0,0 +1,240 @@
+use test_utils::*;
+
+assert_docbook!(
+  paragraphs,
+  adoc! {"
+    foo
+    bar
+
+    [#p1.lead]
+    .Title
+    baz
+  "},
+  html! {r#"
+    <simpara>foo bar</simpara>
+    <formalpara xml:id="p1" role="lead"><title>Title</title><para>baz</para></formalpara>
+  "#}
+);
+
+assert_docbook!(
+  admonitions,
+  adoc! {"
+    NOTE: take note
+
+    .Careful
+    [WARNING]
+    watch out
+
+    [TIP]
+    ====
+    compound
+    ====
+  "},
+  html! {r#"
+    <note><simpara>take note</simpara></note>
+    <warning><title>Careful</title><simpara>watch out</simpara></warning>
+    <tip><simpara>compound</simpara></tip>
+  "#}
+);
+
+assert_docbook!(
+  listing_blocks,
+  adoc! {r#"
+    [source,ruby]
+    ----
+    puts "hi" if x < 1
+    ----
+
+    .Output
+    ----
+    hi
+    there
+    ----
+  "#},
+  html! {
+    r#"
+      <programlisting language="ruby" linenumbering="unnumbered">puts "hi" if x &lt; 1</programlisting>
+      <formalpara><title>Output</title><para><screen>{}</screen></para></formalpara>
+    "#,
+    "hi\nthere"
+  }
+);
+
+assert_docbook!(
+  listing_w_callouts,
+  adoc! {r#"
+    [source,ruby]
+    ----
+    require 'sinatra' <1>
+    get '/hi' do <2>
+    ----
+    <1> Library import
+    <2> URL mapping
+  "#},
+  html! {
+    r#"
+      <programlisting language="ruby" linenumbering="unnumbered">{}</programlisting>
+      <calloutlist>
+        <callout arearefs="CO1-1"><para>Library import</para></callout>
+        <callout arearefs="CO1-2"><para>URL mapping</para></callout>
+      </calloutlist>
+    "#,
+    r#"
+      require 'sinatra' <co xml:id="CO1-1"/>
+      get '/hi' do <co xml:id="CO1-2"/>
+    "#
+  }
+);
+
+assert_docbook!(
+  delimited_blocks,
+  adoc! {"
+    .Ex
+    ====
+    foo
+    ====
+
+    ****
+    bar
+    ****
+
+    [quote,Abe Lincoln,Gettysburg]
+    ____
+    Four score
+    ____
+  "},
+  html! {r#"
+    <example><title>Ex</title><simpara>foo</simpara></example>
+    <sidebar><simpara>bar</simpara></sidebar>
+    <blockquote>
+    <attribution>Abe Lincoln<citetitle>Gettysburg</citetitle></attribution>
+    <simpara>Four score</simpara>
+    </blockquote>
+  "#}
+);
+
+assert_docbook!(
+  image_block,
+  adoc! {"
+    :imagesdir: img
+
+    .A tiger
+    image::tiger.png[Tiger,200]
+  "},
+  html! {r#"
+    <figure>
+    <title>A tiger</title>
+    <mediaobject>
+    <imageobject><imagedata fileref="img/tiger.png" contentwidth="200"/></imageobject>
+    <textobject><phrase>Tiger</phrase></textobject>
+    </mediaobject>
+    </figure>
+  "#}
+);
+
+assert_docbook!(
+  image_alt_w_markup_uses_plain_text,
+  adoc! {"
+    image::tiger.png[alt <x> _y_]
+  "},
+  html! {r#"
+    <informalfigure>
+    <mediaobject>
+    <imageobject><imagedata fileref="tiger.png"/></imageobject>
+    <textobject><phrase>alt &lt;x&gt; y</phrase></textobject>
+    </mediaobject>
+    </informalfigure>
+  "#}
+);
+
+assert_docbook!(
+  table_frame_escaped,
+  adoc! {r#"
+    [frame='x"y']
+    |===
+    |a
+    |===
+  "#},
+  html! {r#"
+    <informaltable frame="x&quot;y" rowsep="1" colsep="1">
+    <tgroup cols="1">
+    <colspec colname="col_1" colwidth="100*"/>
+    <tbody>
+    <row>
+    <entry align="left" valign="top"><simpara>a</simpara></entry>
+    </row>
+    </tbody>
+    </tgroup>
+    </informaltable>
+  "#}
+);
+
+assert_docbook!(
+  simple_table,
+  adoc! {r#"
+    [cols="1,3"]
+    |===
+    |a |b
+
+    |c |d
+    |===
+  "#},
+  html! {r#"
+    <informaltable frame="all" rowsep="1" colsep="1">
+    <tgroup cols="2">
+    <colspec colname="col_1" colwidth="25*"/>
+    <colspec colname="col_2" colwidth="75*"/>
+    <thead>
+    <row>
+    <entry align="left" valign="top">a</entry>
+    <entry align="left" valign="top">b</entry>
+    </row>
+    </thead>
+    <tbody>
+    <row>
+    <entry align="left" valign="top"><simpara>c</simpara></entry>
+    <entry align="left" valign="top"><simpara>d</simpara></entry>
+    </row>
+    </tbody>
+    </tgroup>
+    </informaltable>
+  "#}
+);
+
+assert_docbook!(
+  stem_cdata_not_escaped,
+  adoc! {r#"
+    :stem: latexmath
+
+    [stem]
+    ++++
+    a < b & c ]]> d
+    ++++
+
+    Inline stem:[x > y].
+  "#},
+  html! {r#"
+    <informalequation><mathphrase><![CDATA[a < b & c ]]]]><![CDATA[> d]]></mathphrase></informalequation>
+    <simpara>Inline <inlineequation><mathphrase><![CDATA[x > y]]></mathphrase></inlineequation>.</simpara>
+  "#}
+);
+
+assert_docbook!(
+  attributions_escaped,
+  adoc! {r#"
+    [quote,"Tom & Jerry","<Cartoons>"]
+    ____
+    Chase
+    ____
+
+    "Run"
+    -- Jerry & Co., <Shorts>
+  "#},
+  html! {r#"
+    <blockquote>
+    <attribution>Tom &amp; Jerry<citetitle>&lt;Cartoons&gt;</citetitle></attribution>
+    <simpara>Chase</simpara>
+    </blockquote>
+    <blockquote><attribution>Jerry &amp; Co.<citetitle>&lt;Shorts&gt;</citetitle></attribution><simpara>Run</simpara></blockquote>
+  "#}
+);
@@ -0,0 +1,99 @@
+use asciidork_meta::JobSettings;
+use test_utils::*;
+
+assert_docbook!(
+  standalone_article,
+  |settings: &mut JobSettings| settings.embedded = false,
+  adoc! {"
+    = Doc Title
+
+    == Section 1
+
+    foo
+  "},
+  html! {r#"
+    <?xml version="1.0" encoding="UTF-8"?>
+    <article xmlns="http://docbook.org/ns/docbook" xmlns:xl="http://www.w3.org/1999/xlink" version="5.0" xml:lang="en">
+    <info><title>Doc Title</title></info>
+    <section xml:id="_section_1">
+    <title>Section 1</title>
+    <simpara>foo</simpara>
+    </section>
+    </article>
+  "#}
+);
+
+assert_docbook!(
+  standalone_book,
+  |settings: &mut JobSettings| {
+    settings.embedded = false;
+    settings.doctype = Some(asciidork_meta::DocType::Book);
+  },
+  adoc! {"
+    = Book Title
+    :toc:
+
+    == Chapter
+
+    [appendix]
+    == Extras
+  "},
+  html! {r#"
+    <?xml version="1.0" encoding="UTF-8"?>
+    <?asciidoc-toc?>
+    <book xmlns="http://docbook.org/ns/docbook" xmlns:xl="http://www.w3.org/1999/xlink" version="5.0" xml:lang="en">
+    <info><title>Book Title</title></info>
+    <chapter xml:id="_chapter"><title>Chapter</title></chapter>
+    <appendix xml:id="_extras"><title>Extras</title></appendix>
+    </book>
+  "#}
+);
+
+assert_docbook!(
+  nested_sections_and_inlines,
+  adoc! {"
+    == Outer
+
+    *bold* _italic_ `mono` #marked# H~2~O x^2^ a & b
+
+    === Inner
+
+    See <<_outer>> and https://example.com[the site].
+  "},
+  html! {r#"
+    <section xml:id="_outer">
+    <title>Outer</title>
+    <simpara><emphasis role="strong">bold</emphasis> <emphasis>italic</emphasis> <literal>mono</literal> <emphasis role="marked">marked</emphasis> H<subscript>2</subscript>O x<superscript>2</superscript> a &amp; b</simpara>
+    <section xml:id="_inner">
+    <title>Inner</title>
+    <simpara>See <link linkend="_outer">Outer</link> and <link xl:href="https://example.com">the site</link>.</simpara>
+    </section>
+    </section>
+  "#}
+);
+
+assert_docbook!(
+  interdocument_xrefs,
+  "See <<other.adoc#id,Other>> and xref:guide.adoc[].",
+  html! {r#"
+    <simpara>See <link xl:href="other.xml#id">Other</link> and <link xl:href="guide.xml">guide.xml</link>.</simpara>
+  "#}
+);
+
+assert_docbook!(
+  standalone_lang_escaped,
+  |settings: &mut JobSettings| settings.embedded = false,
+  adoc! {r#"
+    = Doc Title
+    :lang: x"y
+
+    foo
+  "#},
+  html! {r#"
+    <?xml version="1.0" encoding="UTF-8"?>
+    <article xmlns="http://docbook.org/ns/docbook" xmlns:xl="http://www.w3.org/1999/xlink" version="5.0" xml:lang="x&quot;y">
+    <info><title>Doc Title</title></info>
+    <simpara>foo</simpara>
+    </article>
+  "#}
+);
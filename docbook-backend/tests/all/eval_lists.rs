@@ -0,0 +1,53 @@
+use test_utils::*;
+
+assert_docbook!(
+  unordered_list,
+  adoc! {"
+    .Things
+    * foo
+    ** nested
+    * bar
+  "},
+  html! {r#"
+    <itemizedlist>
+    <title>Things</title>
+    <listitem>
+    <simpara>foo</simpara>
+    <itemizedlist><listitem><simpara>nested</simpara></listitem></itemizedlist>
+    </listitem>
+    <listitem><simpara>bar</simpara></listitem>
+    </itemizedlist>
+  "#}
+);
+
+assert_docbook!(
+  ordered_list,
+  adoc! {"
+    . one
+    .. sub
+    . two
+  "},
+  html! {r#"
+    <orderedlist numeration="arabic">
+    <listitem>
+    <simpara>one</simpara>
+    <orderedlist numeration="loweralpha"><listitem><simpara>sub</simpara></listitem></orderedlist>
+    </listitem>
+    <listitem><simpara>two</simpara></listitem>
+    </orderedlist>
+  "#}
+);
+
+assert_docbook!(
+  description_list,
+  adoc! {"
+    CPU:: The brain
+    RAM:: The memory
+  "},
+  html! {r#"
+    <variablelist>
+    <varlistentry><term>CPU</term><listitem><simpara>The brain</simpara></listitem></varlistentry>
+    <varlistentry><term>RAM</term><listitem><simpara>The memory</simpara></listitem></varlistentry>
+    </variablelist>
+  "#}
+);
@@ -0,0 +1,33 @@
+#![macro_use]
+
+#[macro_export]
+macro_rules! assert_docbook {
+  ($name:ident, $input:expr, $expected:expr) => {
+    assert_docbook!($name, |_| {}, $input, $expected);
+  };
+  ($name:ident, $mod_settings:expr, $input:expr, $expected:expr) => {
+    #[test]
+    fn $name() {
+      let actual = _docbook!($input, $mod_settings);
+      ::test_utils::expect_eq!(actual, $expected.to_string(), from: $input);
+    }
+  };
+}
+
+macro_rules! _docbook {
+  ($input:expr, $mod_settings:expr) => {{
+    let bump = &::asciidork_parser::prelude::Bump::new();
+    let mut settings = ::asciidork_meta::JobSettings::embedded();
+    #[allow(clippy::redundant_closure_call)]
+    $mod_settings(&mut settings);
+    let path = ::asciidork_meta::Path::new("test.adoc");
+    let mut parser = ::asciidork_parser::Parser::from_str(
+      $input,
+      ::asciidork_parser::prelude::SourceFile::Path(path),
+      bump,
+    );
+    parser.apply_job_settings(settings);
+    let document = parser.parse().unwrap().document;
+    ::asciidork_eval::eval(&document, ::asciidork_docbook_backend::DocBook5::new()).unwrap()
+  }};
+}
@@ -0,0 +1,5 @@
+mod helpers;
+
+mod eval_blocks;
+mod eval_document;
+mod eval_lists;
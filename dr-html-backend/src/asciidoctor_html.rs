@@ -1,7 +1,11 @@
 use std::collections::HashSet;
 use std::fmt::Write;
+use std::rc::Rc;
+
+use base64::prelude::*;
 
 use crate::internal::*;
+use crate::pretty;
 use crate::str_util;
 use EphemeralState::*;
 
@@ -11,16 +15,25 @@ pub struct AsciidoctorHtml {
   pub(crate) alt_html: String,
   pub(crate) footnotes: Vec<(u16, String, String)>,
   pub(crate) doc_meta: DocumentMeta,
-  pub(crate) fig_caption_num: usize,
-  pub(crate) table_caption_num: usize,
   pub(crate) list_stack: Vec<bool>,
+  pub(crate) dlist_stack: Vec<DlistStyle>,
   pub(crate) default_newlines: Newlines,
   pub(crate) newlines: Newlines,
   pub(crate) state: HashSet<EphemeralState>,
   pub(crate) autogen_conum: u8,
   pub(crate) in_asciidoc_table_cell: bool,
   pub(crate) section_nums: [u16; 5],
+  pub(crate) toc_section_nums: [u16; 5],
+  pub(crate) appendix_num: usize,
+  pub(crate) toc_appendix_num: usize,
+  pub(crate) appendix_letter: Option<String>,
+  pub(crate) toc_appendix_letter: Option<String>,
   pub(crate) section_num_levels: isize,
+  pub(crate) pretty: bool,
+  pub(crate) css_image_dimensions: bool,
+  pub(crate) highlighter: Option<Rc<dyn Highlighter>>,
+  pub(crate) asset_loader: Option<Rc<dyn AssetLoader>>,
+  pub(crate) code_start: Option<usize>,
 }
 
 impl Backend for AsciidoctorHtml {
@@ -28,10 +41,21 @@ impl Backend for AsciidoctorHtml {
   type Error = Infallible;
 
   fn enter_document(&mut self, document: &Document) {
-    self.doc_meta = document.meta.clone();
+    let parent_meta = std::mem::replace(&mut self.doc_meta, document.meta.clone());
+    if self.in_asciidoc_table_cell {
+      self.doc_meta.inherit_doc_attrs(&parent_meta);
+    }
     self.section_num_levels = document.meta.isize("sectnumlevels").unwrap_or(3);
-    if document.meta.is_true("hardbreaks-option") {
-      self.default_newlines = Newlines::JoinWithBreak
+    if self.doc_meta.is_true("hardbreaks-option") {
+      self.default_newlines = Newlines::JoinWithBreak;
+      self.newlines = Newlines::JoinWithBreak;
+    }
+    match document.meta.str("source-highlighter") {
+      Some("rouge") if self.highlighter.is_none() => {
+        self.highlighter = Some(Rc::new(TokenHighlighter));
+      }
+      Some("highlight.js" | "highlightjs" | "none") => self.highlighter = None,
+      _ => {}
     }
 
     if !self.standalone() {
@@ -41,6 +65,9 @@ impl Backend for AsciidoctorHtml {
     if !document.meta.is_true("nolang") {
       self.push([r#" lang=""#, document.meta.str_or("lang", "en"), "\""]);
     }
+    if let Some(dir) = text_direction(&document.meta) {
+      self.push([r#" dir=""#, dir, "\""]);
+    }
     let encoding = document.meta.str_or("encoding", "UTF-8");
     self.push([r#"><head><meta charset=""#, encoding, r#"">"#]);
     self.push_str(r#"<meta http-equiv="X-UA-Compatible" content="IE=edge">"#);
@@ -87,7 +114,7 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_header(&mut self) {
     if !self.doc_meta.embedded && !self.doc_meta.is_true("noheader") {
-      self.push_str(r#"<div id="header">"#)
+      self.open_doc_wrapper("header");
     }
   }
 
@@ -99,7 +126,7 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_content(&mut self) {
     if !self.doc_meta.embedded {
-      self.push_str(r#"<div id="content">"#)
+      self.open_doc_wrapper("content");
     }
   }
 
@@ -111,7 +138,16 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_footer(&mut self) {
     if !self.doc_meta.embedded && !self.doc_meta.is_true("nofooter") {
-      self.push_str(r#"<div id="footer">"#)
+      self.open_doc_wrapper("footer");
+      if let Some(revnumber) = self.doc_meta.string("revnumber") {
+        self.push_str(r#"<div id="footer-text">"#);
+        if let Some(label) = self.doc_meta.string("version-label") {
+          self.push_str_text_escaped(&label);
+          self.push_ch(' ');
+        }
+        self.push_str_text_escaped(&revnumber);
+        self.push_str("<br></div>");
+      }
     }
   }
 
@@ -135,7 +171,7 @@ impl Backend for AsciidoctorHtml {
     } else {
       self.take_buffer(); // discard
     }
-    self.render_document_authors();
+    self.render_document_details();
   }
 
   fn enter_toc(&mut self, toc: &TableOfContents) {
@@ -165,7 +201,17 @@ impl Backend for AsciidoctorHtml {
     if let Some(id) = &node.id {
       self.push_str(id);
     }
-    self.push_str("\">")
+    self.push_str("\">");
+    if let Some(caption) = self.toc_appendix_caption(node) {
+      self.push_str(&caption);
+    } else if !node.unnumbered && self.should_number(node.level, node.special_sect.as_deref()) {
+      let prefix = section::number_prefix(
+        node.level,
+        &mut self.toc_section_nums,
+        self.toc_appendix_letter.as_deref(),
+      );
+      self.push_str(&prefix);
+    }
   }
 
   fn exit_toc_node(&mut self, _node: &TocNode) {
@@ -204,8 +250,14 @@ impl Backend for AsciidoctorHtml {
     } else {
       self.push(["<h", &level_str, ">"]);
     }
-    if self.should_number_section(section) {
-      let prefix = section::number_prefix(section.level, &mut self.section_nums);
+    if let Some(caption) = self.appendix_caption(section) {
+      self.push_str(&caption);
+    } else if self.should_number_section(section) {
+      let prefix = section::number_prefix(
+        section.level,
+        &mut self.section_nums,
+        self.appendix_letter.as_deref(),
+      );
       self.push_str(&prefix);
     }
   }
@@ -244,6 +296,7 @@ impl Backend for AsciidoctorHtml {
   fn enter_sidebar_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["sidebarblock"], block.meta.attrs.as_ref());
     self.push_str(r#"<div class="content">"#);
+    self.render_block_title(&block.meta);
   }
 
   fn exit_sidebar_block(&mut self, _block: &Block, _content: &BlockContent) {
@@ -252,6 +305,7 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_listing_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["listingblock"], block.meta.attrs.as_ref());
+    self.render_block_title(&block.meta);
     self.push_str(r#"<div class="content"><pre"#);
     if let Some(lang) = self.source_lang(block) {
       self.push([
@@ -259,16 +313,31 @@ impl Backend for AsciidoctorHtml {
         &lang,
         r#"" data-lang=""#,
         &lang,
-        r#"">"#,
+        r#"" translate="no">"#,
       ]);
       self.state.insert(IsSourceBlock);
+      let attrs = block.meta.attrs.as_ref();
+      if self.highlighter.is_some()
+        || self.source_linenums(block)
+        || attrs.is_some_and(|a| a.named("highlight").is_some())
+      {
+        self.code_start = Some(self.html.len());
+      }
     } else {
       self.push_ch('>');
     }
     self.newlines = Newlines::Preserve;
   }
 
-  fn exit_listing_block(&mut self, _block: &Block, _content: &BlockContent) {
+  fn exit_listing_block(&mut self, block: &Block, _content: &BlockContent) {
+    if let Some(start) = self.code_start.take() {
+      let mut code = self.html.split_off(start);
+      if let Some(highlighter) = &self.highlighter {
+        let lang = self.source_lang(block).unwrap_or_default();
+        code = highlighter.highlight(&lang, &code);
+      }
+      self.push_source_lines(block, &code);
+    }
     if self.state.remove(&IsSourceBlock) {
       self.push_str("</code>");
     }
@@ -278,6 +347,7 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_literal_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["literalblock"], block.meta.attrs.as_ref());
+    self.render_block_title(&block.meta);
     self.push_str(r#"<div class="content"><pre>"#);
     self.newlines = Newlines::Preserve;
   }
@@ -287,8 +357,28 @@ impl Backend for AsciidoctorHtml {
     self.newlines = self.default_newlines;
   }
 
-  fn enter_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
-  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+  fn enter_passthrough_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.discard_block_title(&block.meta);
+    self.push_raw_marker(pretty::RAW_START);
+  }
+
+  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.push_raw_marker(pretty::RAW_END);
+  }
+
+  fn enter_stem_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.open_element("div", &["stemblock"], block.meta.attrs.as_ref());
+    self.render_block_title(&block.meta);
+    let (open, _) = self.stem_block_delimiters(block);
+    self.push([r#"<div class="content">"#, open]);
+    self.newlines = Newlines::Preserve;
+  }
+
+  fn exit_stem_block(&mut self, block: &Block, _content: &BlockContent) {
+    let (_, close) = self.stem_block_delimiters(block);
+    self.push([close, "</div></div>"]);
+    self.newlines = self.default_newlines;
+  }
 
   fn enter_quoted_paragraph(&mut self, block: &Block, _attr: &str, _cite: Option<&str>) {
     self.open_element("div", &["quoteblock"], block.meta.attrs.as_ref());
@@ -330,11 +420,11 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_example_block(&mut self, block: &Block, _content: &BlockContent) {
     if block.has_attr_option("collapsible") {
-      self.open_element("details", &[], block.meta.attrs.as_ref());
+      let mut open_tag = OpenTag::new("details", block.meta.attrs.as_ref());
       if block.has_attr_option("open") {
-        self.html.pop();
-        self.push_str(" open>");
+        open_tag.set_boolean_attr("open");
       }
+      self.push_open_tag(open_tag);
       self.push_str(r#"<summary class="title">"#);
       if block.meta.title.is_some() {
         self.push_buffered();
@@ -344,6 +434,14 @@ impl Backend for AsciidoctorHtml {
       self.push_str("</summary>");
     } else {
       self.open_element("div", &["exampleblock"], block.meta.attrs.as_ref());
+      let prefix = match block.meta.attr_named("caption") {
+        Some(caption) => Some(Cow::Borrowed(caption)),
+        None => block.meta.numeral.map(|num| {
+          let caption = self.doc_meta.str_or("example-caption", "Example");
+          Cow::Owned(format!("{caption} {num}. "))
+        }),
+      };
+      self.render_prefixed_block_title(&block.meta, prefix);
     }
     self.push_str(r#"<div class="content">"#);
   }
@@ -358,6 +456,7 @@ impl Backend for AsciidoctorHtml {
 
   fn enter_open_block(&mut self, block: &Block, _content: &BlockContent) {
     self.open_element("div", &["openblock"], block.meta.attrs.as_ref());
+    self.render_block_title(&block.meta);
     self.push_str(r#"<div class="content">"#);
   }
 
@@ -366,6 +465,7 @@ impl Backend for AsciidoctorHtml {
   }
 
   fn enter_discrete_heading(&mut self, level: u8, id: Option<&str>, block: &Block) {
+    self.discard_block_title(&block.meta);
     let level_str = num_str!(level + 1);
     if let Some(id) = id {
       self.push(["<h", &level_str, r#" id=""#, id, "\""]);
@@ -415,6 +515,7 @@ impl Backend for AsciidoctorHtml {
   fn enter_callout_list(&mut self, block: &Block, _items: &[ListItem], _depth: u8) {
     self.autogen_conum = 1;
     self.open_element("div", &["colist arabic"], block.meta.attrs.as_ref());
+    self.render_block_title(&block.meta);
     self.push_str(if self.doc_meta.icon_mode() != IconMode::Text { "<table>" } else { "<ol>" });
   }
 
@@ -427,20 +528,75 @@ impl Backend for AsciidoctorHtml {
   }
 
   fn enter_description_list(&mut self, block: &Block, _items: &[ListItem], _depth: u8) {
-    self.open_element("div", &["dlist"], block.meta.attrs.as_ref());
-    self.push_str("<dl>");
+    let attrs = block.meta.attrs.as_ref();
+    let style = match attrs.and_then(|attrs| attrs.str_positional_at(0)) {
+      Some("horizontal") => DlistStyle::Horizontal,
+      Some("qanda") => DlistStyle::Qanda,
+      _ => DlistStyle::Default,
+    };
+    self.dlist_stack.push(style);
+    match style {
+      DlistStyle::Default => {
+        self.open_element("div", &["dlist"], attrs);
+        self.render_block_title(&block.meta);
+        self.push_str("<dl>");
+      }
+      DlistStyle::Horizontal => {
+        self.open_element("div", &["hdlist"], attrs);
+        self.render_block_title(&block.meta);
+        self.push_str("<table>");
+        // NB: widths land in a style attr, so only numbers (w/ optional `%`) are used
+        let col_width = |name| {
+          block
+            .named_attr(name)
+            .map(|width| width.trim_end_matches('%'))
+            .filter(|width| {
+              !width.is_empty() && width.bytes().all(|b| b.is_ascii_digit() || b == b'.')
+            })
+        };
+        let labelwidth = col_width("labelwidth");
+        let itemwidth = col_width("itemwidth");
+        if labelwidth.is_some() || itemwidth.is_some() {
+          self.push_str("<colgroup>");
+          for width in [labelwidth, itemwidth] {
+            match width {
+              Some(width) => self.push([r#"<col style="width: "#, width, "%;\">"]),
+              None => self.push_str("<col>"),
+            }
+          }
+          self.push_str("</colgroup>");
+        }
+      }
+      DlistStyle::Qanda => {
+        self.open_element("div", &["qlist", "qanda"], attrs);
+        self.render_block_title(&block.meta);
+        self.push_str("<ol>");
+      }
+    }
   }
 
   fn exit_description_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
-    self.push_str("</dl></div>");
+    match self.dlist_stack.pop() {
+      Some(DlistStyle::Horizontal) => self.push_str("</table></div>"),
+      Some(DlistStyle::Qanda) => self.push_str("</ol></div>"),
+      _ => self.push_str("</dl></div>"),
+    }
   }
 
   fn enter_description_list_term(&mut self, _item: &ListItem) {
-    self.push_str(r#"<dt class="hdlist1">"#);
+    match self.dlist_stack.last() {
+      Some(DlistStyle::Horizontal) => self.push_str(r#"<tr><td class="hdlist1">"#),
+      Some(DlistStyle::Qanda) => self.push_str("<li><p><em>"),
+      _ => self.push_str(r#"<dt class="hdlist1">"#),
+    }
   }
 
   fn exit_description_list_term(&mut self, _item: &ListItem) {
-    self.push_str("</dt>");
+    match self.dlist_stack.last() {
+      Some(DlistStyle::Horizontal) => self.push_str("</td>"),
+      Some(DlistStyle::Qanda) => self.push_str("</em></p>"),
+      _ => self.push_str("</dt>"),
+    }
   }
 
   fn enter_description_list_description(&mut self, blocks: &[Block], _item: &ListItem) {
@@ -449,11 +605,19 @@ impl Backend for AsciidoctorHtml {
     }) {
       self.state.insert(VisitingSimpleTermDescription);
     }
-    self.push_str("<dd>");
+    match self.dlist_stack.last() {
+      Some(DlistStyle::Horizontal) => self.push_str(r#"<td class="hdlist2">"#),
+      Some(DlistStyle::Qanda) => {}
+      _ => self.push_str("<dd>"),
+    }
   }
 
   fn exit_description_list_description(&mut self, _blocks: &[Block], _item: &ListItem) {
-    self.push_str("</dd>");
+    match self.dlist_stack.last() {
+      Some(DlistStyle::Horizontal) => self.push_str("</td></tr>"),
+      Some(DlistStyle::Qanda) => self.push_str("</li>"),
+      _ => self.push_str("</dd>"),
+    }
   }
 
   fn enter_ordered_list(&mut self, block: &Block, items: &[ListItem], depth: u8) {
@@ -539,8 +703,12 @@ impl Backend for AsciidoctorHtml {
       if !self.state.contains(&VisitingSimpleTermDescription) {
         self.open_element("div", &["paragraph"], block.meta.attrs.as_ref());
         self.render_block_title(&block.meta);
+      } else {
+        self.discard_block_title(&block.meta);
       }
       self.push_str("<p>");
+    } else {
+      self.discard_block_title(&block.meta);
     }
   }
 
@@ -584,6 +752,11 @@ impl Backend for AsciidoctorHtml {
   fn asciidoc_table_cell_backend(&mut self) -> Self {
     Self {
       in_asciidoc_table_cell: true,
+      asset_loader: self.asset_loader.clone(),
+      highlighter: self.highlighter.clone(),
+      css_image_dimensions: self.css_image_dimensions,
+      pretty: self.pretty,
+      doc_meta: self.doc_meta.clone(),
       ..Self::default()
     }
   }
@@ -641,10 +814,12 @@ impl Backend for AsciidoctorHtml {
   }
 
   fn visit_thematic_break(&mut self, block: &Block) {
+    self.discard_block_title(&block.meta);
     self.open_element("hr", &[], block.meta.attrs.as_ref());
   }
 
-  fn visit_page_break(&mut self, _block: &Block) {
+  fn visit_page_break(&mut self, block: &Block) {
+    self.discard_block_title(&block.meta);
     self.push_str(r#"<div style="page-break-after: always;"></div>"#);
   }
 
@@ -680,6 +855,21 @@ impl Backend for AsciidoctorHtml {
     self.push(["[", id, "]"]);
   }
 
+  fn enter_interdoc_xref(&mut self, target: InterdocTarget, _linktext: Option<&[InlineNode]>) {
+    let href = target.href(&self.doc_meta, ".html");
+    self.push_str(r#"<a href=""#);
+    self.push_str_attr_escaped(&href);
+    self.push_str("\">");
+  }
+
+  fn exit_interdoc_xref(&mut self, target: InterdocTarget, linktext: Option<&[InlineNode]>) {
+    if linktext.is_none() {
+      let path = target.out_path(&self.doc_meta, ".html");
+      self.push_str_html_escaped(&path);
+    }
+    self.push_str("</a>");
+  }
+
   fn visit_inline_anchor(&mut self, id: &str) {
     self.push(["<a id=\"", id, "\"></a>"]);
   }
@@ -725,11 +915,29 @@ impl Backend for AsciidoctorHtml {
     self.push_str("</strong>");
   }
 
-  fn enter_inline_passthrough(&mut self, _children: &[InlineNode]) {}
-  fn exit_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+  fn enter_inline_passthrough(&mut self, _children: &[InlineNode]) {
+    self.push_raw_marker(pretty::RAW_START);
+  }
+
+  fn exit_inline_passthrough(&mut self, _children: &[InlineNode]) {
+    self.push_raw_marker(pretty::RAW_END);
+  }
 
   fn visit_button_macro(&mut self, text: &str) {
-    self.push([r#"<b class="button">"#, text, "</b>"])
+    self.push_str(r#"<b class="button">"#);
+    self.push_str_html_escaped(text);
+    self.push_str("</b>");
+  }
+
+  fn visit_stem_macro(&mut self, name: &str, text: &str) {
+    let (open, close) = if self.doc_meta.is_true("stem-fallback") {
+      (r#"<span class="math">"#, "</span>")
+    } else {
+      StemNotation::resolve(name, &self.doc_meta).inline_delimiters()
+    };
+    self.push_str(open);
+    self.push_str_html_escaped(text);
+    self.push_str(close);
   }
 
   fn visit_image_macro(&mut self, target: &str, attrs: &AttrList) {
@@ -742,14 +950,14 @@ impl Backend for AsciidoctorHtml {
 
     let with_link = if let Some(link_href) = attrs.named("link") {
       let mut a_tag = OpenTag::new("a", None);
-      a_tag.push_class("image");
-      a_tag.push_str("\" href=\"");
+      a_tag.push_str(r#" class="image" href=""#);
       if link_href == "self" {
         push_img_path(a_tag.htmlbuf(), target, &self.doc_meta);
       } else {
         a_tag.push_str_attr_escaped(link_href);
       }
-      a_tag.push_link_attrs(attrs, true, false);
+      a_tag.push_ch('"');
+      a_tag.push_link_window_attrs(attrs, false);
       self.push_open_tag(a_tag);
       true
     } else {
@@ -771,7 +979,9 @@ impl Backend for AsciidoctorHtml {
       if idx > 0 {
         self.push_ch('+');
       }
-      self.push(["<kbd>", key, "</kbd>"]);
+      self.push_str("<kbd>");
+      self.push_str_html_escaped(key);
+      self.push_str("</kbd>");
     }
     if keys.len() > 1 {
       self.push_str("</span>");
@@ -788,17 +998,36 @@ impl Backend for AsciidoctorHtml {
   ) {
     let mut tag = OpenTag::new("a", None);
     tag.push_str(" href=\"");
-    if matches!(scheme, Some(UrlScheme::Mailto)) {
+    let mailto = matches!(scheme, Some(UrlScheme::Mailto));
+    if mailto {
       tag.push_str("mailto:");
     }
-    tag.push_str(target);
+    // NB: only relative document paths, anything w/ a uri scheme is left alone,
+    // as are links standing in for includes in secure mode
+    let include = attrs.is_some_and(|attrs| attrs.has_role("include"));
+    match InterdocTarget::parse(target) {
+      Some(doc) if !include && !target.contains(':') && doc.path.ends_with(".adoc") => {
+        tag.push_str(&doc.href(&self.doc_meta, ".html"));
+      }
+      _ => tag.push_str(target),
+    }
+    if let Some(attrs) = attrs.filter(|_| mailto) {
+      let mut sep = if target.contains('?') { "&amp;" } else { "?" };
+      for (name, idx) in [("subject", 1), ("body", 2)] {
+        if let Some(Some(nodes)) = attrs.positional.get(idx) {
+          tag.push([sep, name, "="]);
+          str_util::push_uri_component_encoded(tag.htmlbuf(), &nodes.plain_text().join(""));
+          sep = "&amp;";
+        }
+      }
+    }
     tag.push_ch('"');
 
     if let Some(attrs) = attrs {
-      tag.push_link_attrs(attrs, has_link_text, blank_window_shorthand);
+      tag.push_link_attrs(attrs, has_link_text || mailto, blank_window_shorthand);
     }
 
-    if attrs.is_none() && (!has_link_text && !matches!(scheme, Some(UrlScheme::Mailto))) {
+    if attrs.is_none() && (!has_link_text && !mailto) {
       tag.push_class("bare")
     }
 
@@ -825,21 +1054,26 @@ impl Backend for AsciidoctorHtml {
   }
 
   fn visit_menu_macro(&mut self, items: &[&str]) {
-    let mut items = items.iter();
+    let Some((menu, rest)) = items.split_first() else {
+      return;
+    };
+    let Some((menuitem, submenus)) = rest.split_last() else {
+      self.push_str(r#"<b class="menuref">"#);
+      self.push_str_html_escaped(menu);
+      self.push_str("</b>");
+      return;
+    };
     self.push_str(r#"<span class="menuseq"><span class="menu">"#);
-    self.push_str(items.next().unwrap());
+    self.push_str_html_escaped(menu);
     self.push_str("</span>");
-
-    let last_idx = items.len() - 1;
-    for (idx, item) in items.enumerate() {
-      self.push_str(r#"&#160;&#9656;<span class=""#);
-      if idx == last_idx {
-        self.push(["menuitem\">", item, "</span>"]);
-      } else {
-        self.push(["submenu\">", item, "</span>"]);
-      }
+    for submenu in submenus {
+      self.push_str(r#"&#160;&#9656;<span class="submenu">"#);
+      self.push_str_html_escaped(submenu);
+      self.push_str("</span>");
     }
-    self.push_str("</span>");
+    self.push_str(r#"&#160;&#9656;<span class="menuitem">"#);
+    self.push_str_html_escaped(menuitem);
+    self.push_str("</span></span>");
   }
 
   fn visit_inline_specialchar(&mut self, char: &SpecialCharKind) {
@@ -902,26 +1136,32 @@ impl Backend for AsciidoctorHtml {
     self.push(["<code>", text, "</code>"]);
   }
 
-  fn visit_multichar_whitespace(&mut self, _whitespace: &str) {
-    self.push_ch(' ');
+  fn visit_multichar_whitespace(&mut self, whitespace: &str) {
+    if self.newlines == Newlines::Preserve {
+      self.push_str(whitespace);
+    } else {
+      self.push_ch(' ');
+    }
   }
 
   fn enter_admonition_block(&mut self, kind: AdmonitionKind, block: &Block) {
     let classes = &["admonitionblock", kind.lowercase_str()];
     self.open_element("div", classes, block.meta.attrs.as_ref());
     self.push_str(r#"<table><tr><td class="icon">"#);
+    let label = self.admonition_label(kind, block);
     match self.doc_meta.icon_mode() {
       IconMode::Text => {
-        self.push([r#"<div class="title">"#, kind.str()]);
+        self.push([r#"<div class="title">"#, &label]);
         self.push_str(r#"</div></td><td class="content">"#);
       }
       IconMode::Image => {
-        self.push_admonition_img(kind);
+        self.push_admonition_img(kind, &label);
         self.push_str(r#"</td><td class="content">"#);
       }
       IconMode::Font => {
         self.push([r#"<i class="fa icon-"#, kind.lowercase_str(), "\" title=\""]);
-        self.push([kind.str(), r#""></i></td><td class="content">"#]);
+        self.push_str_attr_escaped(&label);
+        self.push_str(r#""></i></td><td class="content">"#);
       }
     }
     self.render_block_title(&block.meta);
@@ -935,7 +1175,16 @@ impl Backend for AsciidoctorHtml {
     let mut open_tag = OpenTag::new("div", block.meta.attrs.as_ref());
     open_tag.push_class("imageblock");
     open_tag.push_opt_class(img_attrs.named("float"));
-    open_tag.push_opt_prefixed_class(img_attrs.named("align"), Some("text-"));
+    // NB: `align=center` and a `.text-center` role are equivalent, only emit once
+    let align = img_attrs
+      .named("align")
+      .or_else(|| block.named_attr("align"));
+    let align = align.filter(|align| {
+      let class = format!("text-{align}");
+      !img_attrs.has_role(&class) && !block.has_role(&class)
+    });
+    open_tag.push_opt_prefixed_class(align, Some("text-"));
+    open_tag.push_classes(img_attrs.roles.iter());
     self.push_open_tag(open_tag);
 
     self.push_str(r#"<div class="content">"#);
@@ -952,16 +1201,61 @@ impl Backend for AsciidoctorHtml {
   }
 
   fn exit_image_block(&mut self, block: &Block) {
-    let prefix = if self.doc_meta.is_false("figure-caption") {
-      None
-    } else {
-      self.fig_caption_num += 1;
-      Some(Cow::Owned(format!("Figure {}. ", self.fig_caption_num)))
-    };
+    let prefix = block.meta.numeral.map(|num| {
+      let caption = self.doc_meta.str_or("figure-caption", "Figure");
+      Cow::Owned(format!("{caption} {num}. "))
+    });
     self.render_prefixed_block_title(&block.meta, prefix);
     self.push_str(r#"</div>"#);
   }
 
+  fn enter_audio_block(&mut self, target: &str, attrs: &AttrList, block: &Block) {
+    let mut open_tag = OpenTag::new("div", block.meta.attrs.as_ref());
+    open_tag.push_class("audioblock");
+    open_tag.push_classes(attrs.roles.iter());
+    self.push_open_tag(open_tag);
+    self.render_block_title(&block.meta);
+    self.push_str(r#"<div class="content"><audio src=""#);
+    push_img_path(&mut self.html, target, &self.doc_meta);
+    self.push_media_time_anchor(attrs);
+    self.push_ch('"');
+    if attrs.has_option("autoplay") {
+      self.push_str(" autoplay");
+    }
+    if !attrs.has_option("nocontrols") {
+      self.push_str(" controls");
+    }
+    if attrs.has_option("loop") {
+      self.push_str(" loop");
+    }
+    self.push_str(">Your browser does not support the audio tag.</audio></div>");
+  }
+
+  fn exit_audio_block(&mut self, _block: &Block) {
+    self.push_str("</div>");
+  }
+
+  fn enter_video_block(&mut self, target: &str, attrs: &AttrList, block: &Block) {
+    let mut open_tag = OpenTag::new("div", block.meta.attrs.as_ref());
+    open_tag.push_class("videoblock");
+    open_tag.push_opt_class(attrs.named("float"));
+    open_tag.push_opt_prefixed_class(attrs.named("align"), Some("text-"));
+    open_tag.push_classes(attrs.roles.iter());
+    self.push_open_tag(open_tag);
+    self.render_block_title(&block.meta);
+    self.push_str(r#"<div class="content">"#);
+    match attrs.named("poster").or_else(|| attrs.str_positional_at(0)) {
+      Some("youtube") => self.render_youtube_video(target, attrs),
+      Some("vimeo") => self.render_vimeo_video(target, attrs),
+      poster => self.render_video(target, poster, attrs),
+    }
+    self.push_str("</div>");
+  }
+
+  fn exit_video_block(&mut self, _block: &Block) {
+    self.push_str("</div>");
+  }
+
   fn visit_document_attribute_decl(&mut self, name: &str, value: &AttrValue) {
     if name == "hardbreaks-option" {
       if value.is_true() {
@@ -983,7 +1277,9 @@ impl Backend for AsciidoctorHtml {
   fn exit_footnote(&mut self, num: u16, id: Option<&str>, _content: &[InlineNode]) {
     let footnote = self.take_buffer();
     let nums = num.to_string();
-    self.push_str(r#"<sup class="footnote""#);
+    self.push_str(r#"<sup class="footnote"#);
+    self.push_footnote_role();
+    self.push_str("\"");
     if let Some(id) = id {
       self.push([r#" id="_footnote_"#, id, "\""]);
     }
@@ -994,13 +1290,40 @@ impl Backend for AsciidoctorHtml {
     self.footnotes.push((num, id.to_string(), footnote));
   }
 
+  fn visit_footnote_reference(&mut self, num: u16, _id: &str) {
+    let nums = num.to_string();
+    self.push_str(r#"<sup class="footnoteref"#);
+    self.push_footnote_role();
+    self.push_str(r##"">[<a class="footnote" href="#_footnotedef_"##);
+    self.push([&nums, r#"" title="View footnote.">"#, &nums, "</a>]</sup>"]);
+  }
+
+  fn visit_missing_footnote_reference(&mut self, id: &str) {
+    self.push_str(r#"<sup class="footnoteref red" title="Unresolved footnote reference.">["#);
+    self.push_str_html_escaped(id);
+    self.push_str("]</sup>");
+  }
+
   fn into_result(self) -> Result<Self::Output, Self::Error> {
-    Ok(self.html)
+    // NB: cell output is spliced into the parent, which prettifies it all at once
+    if self.pretty && !self.in_asciidoc_table_cell {
+      Ok(pretty::prettify(&self.html))
+    } else {
+      Ok(self.html)
+    }
   }
 
   fn result(&self) -> Result<&Self::Output, Self::Error> {
     Ok(&self.html)
   }
+
+  fn take_flushable_output(&mut self) -> Option<Self::Output> {
+    // NB: prettifying needs the whole document, and buffered output is still pending
+    if self.pretty || self.code_start.is_some() || !self.alt_html.is_empty() {
+      return None;
+    }
+    Some(mem::take(&mut self.html))
+  }
 }
 
 impl HtmlBuf for AsciidoctorHtml {
@@ -1014,6 +1337,34 @@ impl AsciidoctorHtml {
     Self::default()
   }
 
+  /// indents block-level elements in the output, leaving the
+  /// contents of `<pre>` and other verbatim elements untouched
+  pub const fn pretty(mut self) -> Self {
+    self.pretty = true;
+    self
+  }
+
+  /// renders image dimensions as an inline `style` attribute,
+  /// instead of `width` and `height` attributes
+  pub const fn with_css_image_dimensions(mut self) -> Self {
+    self.css_image_dimensions = true;
+    self
+  }
+
+  /// highlights source blocks server-side, unless the document
+  /// opts out via `:source-highlighter: highlight.js` (or `none`)
+  pub fn with_highlighter(mut self, highlighter: Box<dyn Highlighter>) -> Self {
+    self.highlighter = Some(Rc::from(highlighter));
+    self
+  }
+
+  /// loads images embedded via `:data-uri:` with a custom
+  /// loader, instead of reading them from the filesystem
+  pub fn with_asset_loader(mut self, asset_loader: Box<dyn AssetLoader>) -> Self {
+    self.asset_loader = Some(Rc::from(asset_loader));
+    self
+  }
+
   pub fn into_string(self) -> String {
     self.html
   }
@@ -1044,6 +1395,87 @@ impl AsciidoctorHtml {
     }
   }
 
+  fn source_linenums(&self, block: &Block) -> bool {
+    block
+      .meta
+      .attrs
+      .as_ref()
+      .is_some_and(|a| a.str_positional_at(2) == Some("linenums") || a.has_option("linenums"))
+      || self.doc_meta.is_true("source-linenums-option")
+  }
+
+  fn push_source_lines(&mut self, block: &Block, code: &str) {
+    let linenums = self.source_linenums(block);
+    let total_lines = code.split('\n').count();
+    let highlighted = block
+      .meta
+      .attrs
+      .as_ref()
+      .and_then(|a| a.named("highlight"))
+      .map(|input| parse_line_ranges(input, total_lines))
+      .unwrap_or_default();
+    let mut lines = String::with_capacity(code.len() + highlighted.len() * 32);
+    let mut num_lines = 0;
+    for (idx, line) in code.split('\n').enumerate() {
+      if idx > 0 {
+        lines.push('\n');
+      }
+      let num = idx + 1;
+      if highlighted
+        .iter()
+        .any(|(start, end)| (*start..=*end).contains(&num))
+      {
+        lines.push_str(r#"<span class="highlighted">"#);
+        lines.push_str(line);
+        lines.push_str("</span>");
+      } else {
+        lines.push_str(line);
+      }
+      num_lines += 1;
+    }
+    if !linenums {
+      self.push_str(&lines);
+      return;
+    }
+    self.push_str(r#"<table class="linenotable"><tbody><tr><td class="linenos">"#);
+    self.push_str(r#"<pre class="lineno">"#);
+    for num in 1..=num_lines {
+      if num > 1 {
+        self.push_ch('\n');
+      }
+      write!(self.html, "{num}").unwrap();
+    }
+    self.push_str(r#"</pre></td><td class="code"><pre>"#);
+    self.push_str(&lines);
+    self.push_str("</pre></td></tr></tbody></table>");
+  }
+
+  /// NB: `stem-fallback` wraps raw math for static display, in place
+  /// of the delimiters a client-side renderer (e.g. MathJax) looks for
+  fn stem_block_delimiters(&self, block: &Block) -> (&'static str, &'static str) {
+    if self.doc_meta.is_true("stem-fallback") {
+      (r#"<span class="math">"#, "</span>")
+    } else {
+      self.block_stem_notation(block).block_delimiters()
+    }
+  }
+
+  fn block_stem_notation(&self, block: &Block) -> StemNotation {
+    let style = block
+      .meta
+      .attrs
+      .as_ref()
+      .and_then(|a| a.str_positional_at(0));
+    StemNotation::resolve(style.unwrap_or("stem"), &self.doc_meta)
+  }
+
+  /// lets `pretty::prettify` leave raw passthrough content untouched
+  fn push_raw_marker(&mut self, marker: char) {
+    if self.pretty {
+      self.push_ch(marker);
+    }
+  }
+
   fn render_block_title(&mut self, meta: &ChunkMeta) {
     if meta.title.is_some() {
       self.push_str(r#"<div class="title">"#);
@@ -1052,6 +1484,13 @@ impl AsciidoctorHtml {
     }
   }
 
+  /// for blocks that don't render their (already buffered) title
+  fn discard_block_title(&mut self, meta: &ChunkMeta) {
+    if meta.title.is_some() {
+      self.alt_html.clear();
+    }
+  }
+
   fn render_prefixed_block_title(&mut self, meta: &ChunkMeta, prefix: Option<Cow<str>>) {
     if meta.title.is_some() {
       self.push_str(r#"<div class="title">"#);
@@ -1063,25 +1502,60 @@ impl AsciidoctorHtml {
     }
   }
 
+  /// `header`, `content` or `footer` div, w/ classes from `<id>-class` doc attr
+  fn open_doc_wrapper(&mut self, id: &str) {
+    self.push([r#"<div id=""#, id, "\""]);
+    if let Some(class) = self.doc_meta.string(&format!("{id}-class")) {
+      self.push_str(r#" class=""#);
+      self.push_str_attr_escaped(&class);
+      self.push_ch('"');
+    }
+    self.push_ch('>');
+  }
+
   pub(crate) fn open_element(&mut self, element: &str, classes: &[&str], attrs: Option<&AttrList>) {
     let mut open_tag = OpenTag::new(element, attrs);
     classes.iter().for_each(|c| open_tag.push_class(c));
     self.push_open_tag(open_tag);
   }
 
+  /// NB: `:footnote-backlink:` replaces the numbered link back to the
+  /// footnote reference with the given text (e.g. `&#8593;`), and
+  /// `:footnote-role:` adds a class to every footnote superscript
   fn render_footnotes(&mut self) {
     self.push_str(r#"<div id="footnotes"><hr>"#);
+    if let Some(title) = self.doc_meta.string("footnotes-title") {
+      self.push_str(r#"<div class="title">"#);
+      self.push_str_text_escaped(&title);
+      self.push_str("</div>");
+    }
+    let backlink = self.doc_meta.string("footnote-backlink");
     let footnotes = mem::take(&mut self.footnotes);
     for (num, _id, footnote) in &footnotes {
       let num = num.to_string();
       self.push_str(r#"<div class="footnote" id="_footnotedef_"#);
-      self.push([&num, r##""><a href="#_footnoteref_"##, &num, "\">"]);
-      self.push([&num, "</a>. ", footnote, "</div>"]);
+      self.push([&num, r##""><a href="#_footnoteref_"##, &num]);
+      match &backlink {
+        Some(backlink) => {
+          self.push_str(r#"" class="footnote-backlink">"#);
+          self.push_str_text_escaped(backlink);
+          self.push_str("</a> ");
+        }
+        None => self.push(["\">", &num, "</a>. "]),
+      }
+      self.push([footnote.as_str(), "</div>"]);
     }
     self.push_str(r#"</div>"#);
     self.footnotes = footnotes;
   }
 
+  fn push_footnote_role(&mut self) {
+    if let Some(role) = self.doc_meta.string("footnote-role") {
+      self.push_ch(' ');
+      self.push_str_attr_escaped(&role);
+    }
+  }
+
   fn render_favicon(&mut self, meta: &DocumentMeta) {
     match meta.get("favicon") {
       Some(AttrValue::String(path)) => {
@@ -1117,7 +1591,7 @@ impl AsciidoctorHtml {
       self.push_str(title);
     } else if let Some(title) = document.title.as_ref() {
       for s in title.plain_text() {
-        self.push_str(s);
+        self.push_str_html_escaped(s);
       }
     } else {
       self.push_str("Untitled");
@@ -1187,10 +1661,24 @@ impl AsciidoctorHtml {
     self.push([&icondir, "/", prefix.unwrap_or(""), name, ".", &ext]);
   }
 
-  fn push_admonition_img(&mut self, kind: AdmonitionKind) {
+  fn push_admonition_img(&mut self, kind: AdmonitionKind, label: &str) {
     self.push_str(r#"<img src=""#);
     self.push_icon_uri(kind.lowercase_str(), None);
-    self.push([r#"" alt=""#, kind.str(), r#"">"#]);
+    self.push_str(r#"" alt=""#);
+    self.push_str_attr_escaped(label);
+    self.push_str(r#"">"#);
+  }
+
+  /// the `caption` attr, else the `<kind>-caption` doc attr, else the default
+  fn admonition_label(&self, kind: AdmonitionKind, block: &Block) -> String {
+    block
+      .named_attr("caption")
+      .map(str::to_string)
+      .or_else(|| {
+        let doc_attr = format!("{}-caption", kind.lowercase_str());
+        self.doc_meta.string(&doc_attr)
+      })
+      .unwrap_or_else(|| kind.str().to_string())
   }
 
   fn push_callout_number_img(&mut self, num: u8) {
@@ -1206,9 +1694,14 @@ impl AsciidoctorHtml {
     self.push([r#"<b>("#, n_str, ")</b>"]);
   }
 
-  fn render_document_authors(&mut self) {
+  fn render_document_details(&mut self) {
     let authors = self.doc_meta.authors();
-    if self.doc_meta.embedded || authors.is_empty() {
+    let revnumber = self.doc_meta.str("revnumber");
+    let revdate = self.doc_meta.str("revdate");
+    let revremark = self.doc_meta.str("revremark");
+    if self.doc_meta.embedded
+      || (authors.is_empty() && revnumber.is_none() && revdate.is_none() && revremark.is_none())
+    {
       return;
     }
     let mut buffer = String::with_capacity(authors.len() * 100);
@@ -1233,6 +1726,29 @@ impl AsciidoctorHtml {
         buffer.push_str(r#"</a></span><br>"#);
       }
     }
+    if let Some(revnumber) = revnumber {
+      buffer.push_str(r#"<span id="revnumber">"#);
+      // NB: asciidoctor lowercases the label in the byline, not the footer
+      if let Some(label) = self.doc_meta.str("version-label") {
+        buffer.push_str_text_escaped(&label.to_lowercase());
+        buffer.push(' ');
+      }
+      buffer.push_str_text_escaped(revnumber);
+      if revdate.is_some() {
+        buffer.push(',');
+      }
+      buffer.push_str("</span><br>");
+    }
+    if let Some(revdate) = revdate {
+      buffer.push_str(r#"<span id="revdate">"#);
+      buffer.push_str_text_escaped(revdate);
+      buffer.push_str("</span><br>");
+    }
+    if let Some(revremark) = revremark {
+      buffer.push_str(r#"<br><span id="revremark">"#);
+      buffer.push_str_text_escaped(revremark);
+      buffer.push_str("</span><br>");
+    }
     self.push([&buffer, "</div>"]);
   }
 
@@ -1279,7 +1795,9 @@ impl AsciidoctorHtml {
       return self.render_interactive_svg(target, attrs);
     }
     self.push_str(r#"<img src=""#);
-    push_img_path(&mut self.html, target, &self.doc_meta);
+    if !self.push_img_data_uri(target, format) {
+      push_img_path(&mut self.html, target, &self.doc_meta);
+    }
     self.push_str(r#"" alt=""#);
     if let Some(alt) = attrs.named("alt").or_else(|| attrs.str_positional_at(0)) {
       self.push_str_attr_escaped(alt);
@@ -1292,11 +1810,248 @@ impl AsciidoctorHtml {
       self.push_str_attr_escaped(&alt);
     }
     self.push_ch('"');
-    self.push_named_or_pos_attr("width", 1, attrs);
-    self.push_named_or_pos_attr("height", 2, attrs);
+    if self.css_image_dimensions {
+      self.push_img_dimension_styles(attrs);
+    } else {
+      self.push_named_or_pos_attr("width", 1, attrs);
+      self.push_named_or_pos_attr("height", 2, attrs);
+    }
     self.push_named_attr("title", attrs);
     self.push_ch('>');
   }
+
+  fn push_img_dimension_styles(&mut self, attrs: &AttrList) {
+    let mut first = true;
+    for (name, pos) in [("width", 1), ("height", 2)] {
+      if let Some(value) = attrs.named(name).or_else(|| attrs.str_positional_at(pos)) {
+        self.push_str(if first { r#" style=""# } else { ";" });
+        first = false;
+        self.push([name, ":"]);
+        self.push_str_attr_escaped(value);
+        if value.bytes().all(|b| b.is_ascii_digit()) {
+          self.push_str("px");
+        }
+      }
+    }
+    if !first {
+      self.push_ch('"');
+    }
+  }
+
+  /// embeds a local image when `:data-uri:` is set, returns false
+  /// if not embedded, so the caller can fall back to the image path
+  fn push_img_data_uri(&mut self, target: &str, format: Option<&str>) -> bool {
+    if !self.doc_meta.is_true("data-uri")
+      || self.doc_meta.safe_mode == SafeMode::Secure
+      || target.starts_with("data:")
+    {
+      return false;
+    }
+    let mimetype = match format.map(str::to_lowercase).as_deref() {
+      Some("svg") => "svg+xml",
+      Some("jpg" | "jpeg") => "jpeg",
+      Some("png") => "png",
+      Some("gif") => "gif",
+      Some("webp") => "webp",
+      Some("avif") => "avif",
+      Some("bmp") => "bmp",
+      Some("ico") => "x-icon",
+      Some("tif" | "tiff") => "tiff",
+      _ => return false,
+    };
+    let path = match self.doc_meta.str("imagesdir") {
+      Some(imagesdir) if !Path::is_absolute_or_uri(target) => Path::new(imagesdir).join(target),
+      _ => Path::new(target),
+    };
+    if Path::has_uri_scheme(&path.to_string()) {
+      return false;
+    }
+    let jail = Path::new(self.doc_meta.str("docdir").unwrap_or(""));
+    let path = jail.join(path);
+    // NB: as in asciidoctor, only unsafe mode may read files outside the docdir
+    if self.doc_meta.safe_mode > SafeMode::Unsafe && !path.is_within(&jail) {
+      return false;
+    }
+    let path = path.to_string();
+    let bytes = match &self.asset_loader {
+      Some(loader) => loader.load(&path),
+      None => FsAssetLoader.load(&path),
+    };
+    let Some(bytes) = bytes else {
+      return false;
+    };
+    self.push(["data:image/", mimetype, ";base64,"]);
+    BASE64_STANDARD.encode_string(bytes, &mut self.html);
+    true
+  }
+
+  fn render_video(&mut self, target: &str, poster: Option<&str>, attrs: &AttrList) {
+    self.push_str(r#"<video src=""#);
+    push_img_path(&mut self.html, target, &self.doc_meta);
+    self.push_media_time_anchor(attrs);
+    self.push_ch('"');
+    self.push_named_or_pos_attr("width", 1, attrs);
+    self.push_named_or_pos_attr("height", 2, attrs);
+    if let Some(poster) = poster.filter(|poster| !poster.is_empty()) {
+      self.push_str(r#" poster=""#);
+      push_img_path(&mut self.html, poster, &self.doc_meta);
+      self.push_ch('"');
+    }
+    if attrs.has_option("autoplay") {
+      self.push_str(" autoplay");
+    }
+    if !attrs.has_option("nocontrols") {
+      self.push_str(" controls");
+    }
+    if attrs.has_option("loop") {
+      self.push_str(" loop");
+    }
+    if attrs.has_option("muted") {
+      self.push_str(" muted");
+    }
+    self.push_named_attr("preload", attrs);
+    self.push_str(">Your browser does not support the video tag.</video>");
+  }
+
+  /// media fragment, e.g. `#t=10,20`, from `start` and `end` attrs
+  fn push_media_time_anchor(&mut self, attrs: &AttrList) {
+    let start = attrs.named("start");
+    let end = attrs.named("end");
+    if start.is_some() || end.is_some() {
+      self.push_str("#t=");
+      str_util::push_uri_component_encoded(&mut self.html, start.unwrap_or_default());
+      if let Some(end) = end {
+        self.push_ch(',');
+        str_util::push_uri_component_encoded(&mut self.html, end);
+      }
+    }
+  }
+
+  fn render_youtube_video(&mut self, target: &str, attrs: &AttrList) {
+    let mut params = String::with_capacity(64);
+    params.push_str(if attrs.has_option("related") { "?rel=1" } else { "?rel=0" });
+    if let Some(start) = attrs.named("start") {
+      params.push_str("&amp;start=");
+      str_util::push_uri_component_encoded(&mut params, start);
+    }
+    if let Some(end) = attrs.named("end") {
+      params.push_str("&amp;end=");
+      str_util::push_uri_component_encoded(&mut params, end);
+    }
+    if attrs.has_option("autoplay") {
+      params.push_str("&amp;autoplay=1");
+    }
+    if attrs.has_option("loop") {
+      params.push_str("&amp;loop=1");
+    }
+    if attrs.has_option("muted") {
+      params.push_str("&amp;mute=1");
+    }
+    if attrs.has_option("nocontrols") {
+      params.push_str("&amp;controls=0");
+    }
+    let (mut video_id, list) = match target.split_once('/') {
+      Some((video_id, list)) => (video_id, Some(list)),
+      None => (target, attrs.named("list")),
+    };
+    if let Some(list) = list {
+      params.push_str("&amp;list=");
+      str_util::push_uri_component_encoded(&mut params, list);
+    } else {
+      let playlist = match video_id.split_once(',') {
+        Some((id, playlist)) => {
+          video_id = id;
+          Some(playlist)
+        }
+        None => attrs.named("playlist"),
+      };
+      if let Some(playlist) = playlist {
+        params.push_str("&amp;playlist=");
+        str_util::push_uri_component_encoded(&mut params, video_id);
+        for id in playlist.split(',') {
+          params.push(',');
+          str_util::push_uri_component_encoded(&mut params, id);
+        }
+      } else if attrs.has_option("loop") {
+        params.push_str("&amp;playlist=");
+        str_util::push_uri_component_encoded(&mut params, video_id);
+      }
+    }
+    let fullscreen = !attrs.has_option("nofullscreen");
+    if !fullscreen {
+      params.push_str("&amp;fs=0");
+    }
+    if attrs.has_option("modest") {
+      params.push_str("&amp;modestbranding=1");
+    }
+    if let Some(theme) = attrs.named("theme") {
+      params.push_str("&amp;theme=");
+      str_util::push_uri_component_encoded(&mut params, theme);
+    }
+    if let Some(lang) = attrs.named("lang") {
+      params.push_str("&amp;hl=");
+      str_util::push_uri_component_encoded(&mut params, lang);
+    }
+    let scheme = self.asset_uri_scheme();
+    self.push_str("<iframe");
+    self.push_named_or_pos_attr("width", 1, attrs);
+    self.push_named_or_pos_attr("height", 2, attrs);
+    self.push([r#" src=""#, &scheme, "//www.youtube.com/embed/"]);
+    str_util::push_uri_component_encoded(&mut self.html, video_id);
+    self.push([&params, r#"" frameborder="0""#]);
+    if fullscreen {
+      self.push_str(" allowfullscreen");
+    }
+    self.push_str("></iframe>");
+  }
+
+  fn render_vimeo_video(&mut self, target: &str, attrs: &AttrList) {
+    let (video_id, hash) = match target.split_once('/') {
+      Some((video_id, hash)) => (video_id, Some(hash)),
+      None => (target, attrs.named("hash")),
+    };
+    let mut params = Vec::with_capacity(4);
+    if let Some(hash) = hash {
+      let mut param = String::from("h=");
+      str_util::push_uri_component_encoded(&mut param, hash);
+      params.push(param);
+    }
+    for (option, param) in [
+      ("autoplay", "autoplay=1"),
+      ("loop", "loop=1"),
+      ("muted", "muted=1"),
+    ] {
+      if attrs.has_option(option) {
+        params.push(param.to_string());
+      }
+    }
+    let scheme = self.asset_uri_scheme();
+    self.push_str("<iframe");
+    self.push_named_or_pos_attr("width", 1, attrs);
+    self.push_named_or_pos_attr("height", 2, attrs);
+    self.push([r#" src=""#, &scheme, "//player.vimeo.com/video/"]);
+    str_util::push_uri_component_encoded(&mut self.html, video_id);
+    if !params.is_empty() {
+      self.push(["?", &params.join("&amp;")]);
+    }
+    if let Some(start) = attrs.named("start") {
+      self.push_str("#at=");
+      str_util::push_uri_component_encoded(&mut self.html, start);
+    }
+    self.push_str(r#"" frameborder="0""#);
+    if !attrs.has_option("nofullscreen") {
+      self.push_str(" allowfullscreen");
+    }
+    self.push_str("></iframe>");
+  }
+
+  fn asset_uri_scheme(&self) -> String {
+    match self.doc_meta.str("asset-uri-scheme") {
+      Some("") => String::new(),
+      Some(scheme) => format!("{scheme}:"),
+      None => "https:".to_string(),
+    }
+  }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
@@ -1307,12 +2062,54 @@ pub enum Newlines {
   Preserve,
 }
 
+// NB: a valid explicit `dir` attr wins, otherwise infer `rtl` from the primary `lang` subtag
+fn text_direction(meta: &DocumentMeta) -> Option<&'static str> {
+  match meta.str("dir").map(str::to_ascii_lowercase).as_deref() {
+    Some("ltr") => return Some("ltr"),
+    Some("rtl") => return Some("rtl"),
+    Some("auto") => return Some("auto"),
+    _ => {}
+  }
+  let lang = meta.str("lang")?;
+  let primary = lang.split(['-', '_']).next().unwrap_or(lang);
+  match primary {
+    "ar" | "dv" | "fa" | "he" | "ps" | "sd" | "ug" | "ur" | "yi" => Some("rtl"),
+    _ => None,
+  }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DlistStyle {
+  Default,
+  Horizontal,
+  Qanda,
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EphemeralState {
   VisitingSimpleTermDescription,
   IsSourceBlock,
 }
 
+/// parses `highlight` attr values like `1,3..5`
+/// parses `highlight` line specs (e.g. `1,3..5,8..`) into inclusive
+/// ranges, with open-ended or oversized ranges clamped to `max_line`
+fn parse_line_ranges(input: &str, max_line: usize) -> Vec<(usize, usize)> {
+  let mut ranges = Vec::new();
+  for part in input.split(',').map(str::trim) {
+    let range = match part.split_once("..") {
+      Some((start, "")) => start.trim().parse().ok().map(|start| (start, max_line)),
+      Some((start, end)) => match (start.trim().parse(), end.trim().parse::<usize>()) {
+        (Ok(start), Ok(end)) => Some((start, end.min(max_line))),
+        _ => None,
+      },
+      None => part.parse().ok().map(|num| (num, num)),
+    };
+    ranges.extend(range.filter(|(start, end)| start <= end));
+  }
+  ranges
+}
+
 const fn list_type_from_depth(depth: u8) -> &'static str {
   match depth {
     1 => "1",
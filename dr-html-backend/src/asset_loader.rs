@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+/// loads the bytes of local assets (images, etc.) for embedding as
+/// data uris, consumers without a filesystem (wasm) can supply their own
+pub trait AssetLoader: Debug {
+  fn load(&self, path: &str) -> Option<Vec<u8>>;
+}
+
+/// default loader, reads assets from the filesystem
+#[derive(Debug, Default, Clone, Copy)]
+pub struct FsAssetLoader;
+
+impl AssetLoader for FsAssetLoader {
+  fn load(&self, path: &str) -> Option<Vec<u8>> {
+    std::fs::read(path).ok()
+  }
+}
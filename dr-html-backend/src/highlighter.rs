@@ -0,0 +1,139 @@
+use std::fmt::Debug;
+
+/// server-side syntax highlighting for source blocks
+pub trait Highlighter: Debug {
+  /// NB: `code` is already html-escaped, and may contain markup
+  /// (e.g. callouts) which must be passed through untouched
+  fn highlight(&self, lang: &str, code: &str) -> String;
+}
+
+/// minimal built-in highlighter used for `:source-highlighter: rouge`,
+/// wraps keywords, strings, numbers and comments in `tok-*` spans
+#[derive(Debug, Default, Clone, Copy)]
+pub struct TokenHighlighter;
+
+impl Highlighter for TokenHighlighter {
+  fn highlight(&self, lang: &str, code: &str) -> String {
+    let hash_comments = matches!(
+      lang,
+      "ruby" | "python" | "py" | "sh" | "bash" | "shell" | "yaml" | "yml" | "toml" | "perl" | "r"
+    );
+    let single_quoted_strings =
+      hash_comments || matches!(lang, "js" | "javascript" | "ts" | "typescript" | "php");
+    let mut out = String::with_capacity(code.len() * 2);
+    let mut rest = code;
+    // depth of markup elements (callouts, etc.) we're inside of
+    let mut depth = 0_usize;
+    while let Some(ch) = rest.chars().next() {
+      let (len, kind) = match ch {
+        '<' => {
+          let len = rest.find('>').map_or(rest.len(), |idx| idx + 1);
+          if rest.starts_with("</") {
+            depth = depth.saturating_sub(1);
+          } else if !rest[..len].ends_with("/>") {
+            depth += 1;
+          }
+          (len, None)
+        }
+        _ if depth > 0 => (rest.find('<').unwrap_or(rest.len()), None),
+        '&' => (rest.find(';').map_or(1, |idx| idx + 1), None),
+        '#' if hash_comments => (until_markup_or_eol(rest), Some("cmt")),
+        '/' if !hash_comments && rest.starts_with("//") => (until_markup_or_eol(rest), Some("cmt")),
+        '"' => (quoted(rest, '"'), Some("str")),
+        '\'' if single_quoted_strings => (quoted(rest, '\''), Some("str")),
+        '0'..='9' => (
+          rest
+            .find(|c: char| !c.is_ascii_alphanumeric() && c != '.' && c != '_')
+            .unwrap_or(rest.len()),
+          Some("num"),
+        ),
+        c if c.is_alphabetic() || c == '_' => {
+          let len = rest
+            .find(|c: char| !c.is_alphanumeric() && c != '_')
+            .unwrap_or(rest.len());
+          (len, KEYWORDS.contains(&&rest[..len]).then_some("kw"))
+        }
+        c => (c.len_utf8(), None),
+      };
+      let (token, remaining) = rest.split_at(len);
+      if let Some(kind) = kind {
+        out.push_str("<span class=\"tok-");
+        out.push_str(kind);
+        out.push_str("\">");
+        out.push_str(token);
+        out.push_str("</span>");
+      } else {
+        out.push_str(token);
+      }
+      rest = remaining;
+    }
+    out
+  }
+}
+
+fn until_markup_or_eol(input: &str) -> usize {
+  input.find(['<', '\n']).unwrap_or(input.len())
+}
+
+fn quoted(input: &str, quote: char) -> usize {
+  let mut escaped = false;
+  for (idx, c) in input.char_indices().skip(1) {
+    match c {
+      '<' | '\n' => return idx,
+      '\\' => escaped = !escaped,
+      c if c == quote && !escaped => return idx + 1,
+      _ => escaped = false,
+    }
+  }
+  input.len()
+}
+
+const KEYWORDS: &[&str] = &[
+  "as", "async", "await", "break", "case", "catch", "class", "const", "continue", "def", "do",
+  "elif", "else", "end", "enum", "export", "extends", "false", "fn", "for", "from", "func",
+  "function", "if", "impl", "import", "in", "let", "loop", "match", "mod", "module", "mut", "new",
+  "nil", "None", "null", "pub", "require", "return", "self", "static", "struct", "super", "switch",
+  "this", "throw", "trait", "True", "true", "try", "type", "use", "var", "while", "yield",
+];
+
+// tests
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_utils::*;
+
+  #[test]
+  fn test_token_highlighter() {
+    let cases = vec![
+      (
+        "ruby",
+        "def foo # hi",
+        r#"<span class="tok-kw">def</span> foo <span class="tok-cmt"># hi</span>"#,
+      ),
+      (
+        "rust",
+        "let x = 42; // &lt;ok&gt;",
+        r#"<span class="tok-kw">let</span> x = <span class="tok-num">42</span>; <span class="tok-cmt">// &lt;ok&gt;</span>"#,
+      ),
+      (
+        "js",
+        r#"f("a\"b", 'c')"#,
+        r#"f(<span class="tok-str">"a\"b"</span>, <span class="tok-str">'c'</span>)"#,
+      ),
+      (
+        "ruby",
+        r#"puts "x <b class="conum">(1)</b>"#,
+        r#"puts <span class="tok-str">"x </span><b class="conum">(1)</b>"#,
+      ),
+      (
+        "rust",
+        "letter &amp; r#in",
+        "letter &amp; r#<span class=\"tok-kw\">in</span>",
+      ),
+    ];
+    for (lang, input, expected) in cases {
+      expect_eq!(TokenHighlighter.highlight(lang, input), expected, from: input);
+    }
+  }
+}
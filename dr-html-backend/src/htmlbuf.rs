@@ -16,6 +16,31 @@ pub trait HtmlBuf {
     }
   }
 
+  fn push_str_html_escaped(&mut self, s: &str) {
+    for c in s.chars() {
+      match c {
+        '&' => self.htmlbuf().push_str("&amp;"),
+        '<' => self.htmlbuf().push_str("&lt;"),
+        '>' => self.htmlbuf().push_str("&gt;"),
+        _ => self.htmlbuf().push(c),
+      }
+    }
+  }
+
+  /// like `push_str_html_escaped`, but leaves character references
+  /// (e.g. `&#8593;` or `&hellip;`) intact, for document attribute values
+  fn push_str_text_escaped(&mut self, s: &str) {
+    for (idx, c) in s.char_indices() {
+      match c {
+        '&' if char_ref_len(&s[idx..]).is_some() => self.htmlbuf().push(c),
+        '&' => self.htmlbuf().push_str("&amp;"),
+        '<' => self.htmlbuf().push_str("&lt;"),
+        '>' => self.htmlbuf().push_str("&gt;"),
+        _ => self.htmlbuf().push(c),
+      }
+    }
+  }
+
   fn push_url_encoded(&mut self, s: &str) {
     push_url_encoded(self.htmlbuf(), s);
   }
@@ -53,8 +78,15 @@ pub trait HtmlBuf {
   }
 }
 
+impl HtmlBuf for String {
+  fn htmlbuf(&mut self) -> &mut String {
+    self
+  }
+}
+
 pub fn push_img_path(buf: &mut String, target: &str, doc_meta: &DocumentMeta) {
-  if let Some(imagesdir) = doc_meta.str("imagesdir") {
+  let is_absolute = Path::is_absolute_or_uri(target);
+  if let Some(imagesdir) = doc_meta.str("imagesdir").filter(|_| !is_absolute) {
     let mut path = Path::new(imagesdir);
     path.push(target);
     push_url_encoded(buf, &path.to_string());
@@ -63,10 +95,30 @@ pub fn push_img_path(buf: &mut String, target: &str, doc_meta: &DocumentMeta) {
   }
 }
 
+/// length of the character reference `s` starts with, if any
+fn char_ref_len(s: &str) -> Option<usize> {
+  let end = s.find(';')?;
+  let name = &s[1..end];
+  let valid = match name.strip_prefix('#') {
+    Some(num) => match num.strip_prefix(['x', 'X']) {
+      Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+      None => !num.is_empty() && num.chars().all(|c| c.is_ascii_digit()),
+    },
+    None => {
+      name.starts_with(|c: char| c.is_ascii_alphabetic())
+        && name.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+  };
+  valid.then_some(end + 1)
+}
+
 fn push_url_encoded(buf: &mut String, s: &str) {
   for c in s.chars() {
     match c {
       ' ' => buf.push_str("%20"),
+      '"' => buf.push_str("%22"),
+      '<' => buf.push_str("%3C"),
+      '>' => buf.push_str("%3E"),
       _ => buf.push(c),
     }
   }
@@ -1,4 +1,6 @@
 use std::error::Error;
+use std::path::PathBuf;
+use std::rc::Rc;
 
 extern crate asciidork_ast as ast;
 extern crate asciidork_backend as backend;
@@ -6,17 +8,61 @@ extern crate asciidork_eval as eval;
 extern crate asciidork_meta as meta;
 
 mod asciidoctor_html;
+mod asset_loader;
+mod highlighter;
 mod htmlbuf;
 mod open_tag;
+mod pretty;
 pub mod section;
 mod table;
 
 pub use asciidoctor_html::AsciidoctorHtml;
+pub use asset_loader::{AssetLoader, FsAssetLoader};
+pub use highlighter::{Highlighter, TokenHighlighter};
 
 pub fn convert(document: ast::Document) -> Result<String, Box<dyn Error>> {
   Ok(eval::eval(&document, AsciidoctorHtml::new())?)
 }
 
+/// embedded html, along with the local assets it references
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Fragment {
+  pub html: String,
+  /// image and stylesheet paths (resolved against `imagesdir` and
+  /// `stylesdir`) in order of first reference, excluding remote urls
+  pub assets: Vec<PathBuf>,
+}
+
+/// `assets` are as reported by the parser, see `ParseResult::assets`
+pub fn convert_fragment(
+  mut document: ast::Document,
+  assets: Vec<PathBuf>,
+) -> Result<Fragment, Box<dyn Error>> {
+  document.meta.embedded = true;
+  let html = eval::eval(&document, AsciidoctorHtml::new())?;
+  Ok(Fragment { html, assets })
+}
+
+/// renders a single block of a parsed document as embedded html, e.g. for
+/// previews, resolving xrefs and footnote refs against the whole document
+pub fn render_block<'arena>(
+  block: &ast::Block<'arena>,
+  document: &ast::Document<'arena>,
+) -> String {
+  let bump = match &document.content {
+    ast::DocContent::Blocks(blocks) => blocks.bump(),
+    ast::DocContent::Sectioned { sections, .. } => sections.bump(),
+  };
+  let mut shell = ast::Document::new(bump);
+  shell.meta = document.meta.clone();
+  shell.meta.embedded = true;
+  shell.anchors = Rc::clone(&document.anchors);
+  shell.footnotes = Rc::clone(&document.footnotes);
+  let mut backend = AsciidoctorHtml::new();
+  eval::visit_block(block, &shell, &mut backend);
+  backend.into_string()
+}
+
 mod internal {
   pub use std::borrow::Cow;
   pub use std::convert::Infallible;
@@ -25,6 +71,8 @@ mod internal {
   pub use lazy_static::lazy_static;
   pub use regex::Regex;
 
+  pub use crate::asset_loader::*;
+  pub use crate::highlighter::*;
   pub use crate::htmlbuf::*;
   pub use crate::open_tag::*;
   pub use crate::section;
@@ -64,4 +112,20 @@ mod str_util {
       input
     }
   }
+  /// percent-encodes all but unreserved chars (RFC 3986)
+  pub fn push_uri_component_encoded(buf: &mut String, input: &str) {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    for byte in input.bytes() {
+      match byte {
+        b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+          buf.push(byte as char)
+        }
+        _ => {
+          buf.push('%');
+          buf.push(HEX[(byte >> 4) as usize] as char);
+          buf.push(HEX[(byte & 0xF) as usize] as char);
+        }
+      }
+    }
+  }
 }
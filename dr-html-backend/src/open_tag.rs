@@ -10,6 +10,7 @@ pub struct OpenTag {
   opened_classes: bool,
   append_classes: Option<String>,
   styles: Option<String>,
+  boolean_attrs: Option<String>,
 }
 
 impl HtmlBuf for OpenTag {
@@ -33,6 +34,7 @@ impl OpenTag {
       opened_classes: false,
       append_classes: None,
       styles: None,
+      boolean_attrs: None,
     };
 
     tag.buf.push('<');
@@ -126,15 +128,28 @@ impl OpenTag {
     }
   }
 
+  // NB: styles are expected to carry their own trailing `;`
   pub fn push_style(&mut self, style: impl AsRef<str>) {
     if self.styles.is_none() {
       self.styles = Some(style.as_ref().to_string());
     } else {
-      self.styles.as_mut().unwrap().push_str("; ");
+      self.styles.as_mut().unwrap().push(' ');
       self.styles.as_mut().unwrap().push_str(style.as_ref());
     }
   }
 
+  // NB: if we're mid-class-attr, defer until `finish()`
+  pub fn set_boolean_attr(&mut self, name: &str) {
+    if !self.opened_classes {
+      self.buf.push(' ');
+      self.buf.push_str(name);
+    } else {
+      let deferred = self.boolean_attrs.get_or_insert_with(String::new);
+      deferred.push(' ');
+      deferred.push_str(name);
+    }
+  }
+
   pub fn push_link_attrs(
     &mut self,
     attrs: &AttrList,
@@ -149,6 +164,10 @@ impl OpenTag {
       self.buf.push('"');
       self.opened_classes = false;
     }
+    self.push_link_window_attrs(attrs, blank_window_shorthand);
+  }
+
+  pub fn push_link_window_attrs(&mut self, attrs: &AttrList, blank_window_shorthand: bool) {
     if let Some(target) = attrs.named("window") {
       self.push_str(" target=\"");
       self.push_str(target);
@@ -186,6 +205,9 @@ impl OpenTag {
       self.buf.push_str(&styles);
       self.buf.push('"');
     }
+    if let Some(boolean_attrs) = self.boolean_attrs.take() {
+      self.buf.push_str(&boolean_attrs);
+    }
     self.buf.push('>');
     self.buf
   }
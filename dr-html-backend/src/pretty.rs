@@ -0,0 +1,196 @@
+// NB: this is a post-processing pass over the (minified) html emitted
+// by the backend, so it only has to understand the subset of html
+// that we produce ourselves. verbatim elements are copied untouched,
+// as is raw passthrough content, which the backend brackets in markers
+
+const INDENT: &str = "  ";
+
+/// noncharacters (reserved for internal use) delimiting raw passthrough
+/// content, they never reach the prettified output
+pub(crate) const RAW_START: char = '\u{FDD0}';
+pub(crate) const RAW_END: char = '\u{FDD1}';
+
+pub(crate) fn prettify(html: &str) -> String {
+  let mut out = String::with_capacity(html.len() + html.len() / 4);
+  // (tag name, has block-level children)
+  let mut stack: Vec<(&str, bool)> = Vec::with_capacity(16);
+  let mut rest = html;
+
+  while let Some(pos) = rest.find(['<', RAW_START]) {
+    push_unmarked(&mut out, &rest[..pos]);
+    rest = &rest[pos..];
+    if let Some(raw) = rest.strip_prefix(RAW_START) {
+      let end = raw_len(raw);
+      push_unmarked(&mut out, &raw[..end]);
+      rest = raw[end..].strip_prefix(RAW_END).unwrap_or("");
+      continue;
+    }
+    let Some(gt) = rest.find('>') else {
+      break;
+    };
+    let tag = &rest[..=gt];
+    rest = &rest[gt + 1..];
+    let closing = tag.starts_with("</");
+    let name = tag_name(tag);
+
+    if tag.starts_with("<!") || is_void_block(name) {
+      mark_block_child(&mut stack);
+      push_line(&mut out, stack.len());
+      out.push_str(tag);
+    } else if is_verbatim(name) && !closing {
+      mark_block_child(&mut stack);
+      push_line(&mut out, stack.len());
+      out.push_str(tag);
+      let close_tag = format!("</{}>", name);
+      let end = rest
+        .find(&close_tag)
+        .map_or(rest.len(), |i| i + close_tag.len());
+      push_unmarked(&mut out, &rest[..end]);
+      rest = &rest[end..];
+    } else if is_block(name) && !closing {
+      mark_block_child(&mut stack);
+      push_line(&mut out, stack.len());
+      out.push_str(tag);
+      stack.push((name, false));
+    } else if is_block(name) && stack.last().is_some_and(|(open, _)| *open == name) {
+      let (_, had_block_children) = stack.pop().unwrap();
+      if had_block_children {
+        push_line(&mut out, stack.len());
+      }
+      out.push_str(tag);
+    } else {
+      out.push_str(tag);
+    }
+  }
+  push_unmarked(&mut out, rest);
+  out
+}
+
+/// length of the raw content up to its (possibly nested) end marker
+fn raw_len(raw: &str) -> usize {
+  let mut depth = 0;
+  for (idx, c) in raw.char_indices() {
+    match c {
+      RAW_START => depth += 1,
+      RAW_END if depth == 0 => return idx,
+      RAW_END => depth -= 1,
+      _ => {}
+    }
+  }
+  raw.len()
+}
+
+fn push_unmarked(out: &mut String, s: &str) {
+  if s.contains([RAW_START, RAW_END]) {
+    out.extend(s.chars().filter(|c| !matches!(*c, RAW_START | RAW_END)));
+  } else {
+    out.push_str(s);
+  }
+}
+
+fn push_line(out: &mut String, depth: usize) {
+  if !out.is_empty() {
+    out.push('\n');
+  }
+  for _ in 0..depth {
+    out.push_str(INDENT);
+  }
+}
+
+const fn mark_block_child(stack: &mut [(&str, bool)]) {
+  if let Some((_, has_block_children)) = stack.last_mut() {
+    *has_block_children = true;
+  }
+}
+
+fn tag_name(tag: &str) -> &str {
+  let name = tag.trim_start_matches('<').trim_start_matches('/');
+  let end = name
+    .find(|c: char| c.is_ascii_whitespace() || c == '>' || c == '/')
+    .unwrap_or(name.len());
+  &name[..end]
+}
+
+fn is_verbatim(name: &str) -> bool {
+  matches!(name, "pre" | "textarea" | "script" | "style")
+}
+
+fn is_void_block(name: &str) -> bool {
+  matches!(name, "meta" | "link" | "col" | "hr")
+}
+
+fn is_block(name: &str) -> bool {
+  matches!(
+    name,
+    "html"
+      | "head"
+      | "title"
+      | "body"
+      | "div"
+      | "p"
+      | "h1"
+      | "h2"
+      | "h3"
+      | "h4"
+      | "h5"
+      | "h6"
+      | "table"
+      | "caption"
+      | "colgroup"
+      | "thead"
+      | "tbody"
+      | "tfoot"
+      | "tr"
+      | "th"
+      | "td"
+      | "ul"
+      | "ol"
+      | "li"
+      | "dl"
+      | "dt"
+      | "dd"
+      | "details"
+      | "summary"
+      | "blockquote"
+      | "figure"
+      | "nav"
+      | "section"
+      | "audio"
+      | "video"
+  )
+}
+
+// tests
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_utils::*;
+
+  #[test]
+  fn test_prettify() {
+    let cases = vec![
+      ("<p>foo</p>", "<p>foo</p>"),
+      (
+        r#"<div class="paragraph"><p>foo <em>bar</em></p></div>"#,
+        "<div class=\"paragraph\">\n  <p>foo <em>bar</em></p>\n</div>",
+      ),
+      (
+        "<div><div><p>a</p></div><hr><p>b</p></div>",
+        "<div>\n  <div>\n    <p>a</p>\n  </div>\n  <hr>\n  <p>b</p>\n</div>",
+      ),
+      (
+        "<div><pre><div>\n  x\n</div></pre></div>",
+        "<div>\n  <pre><div>\n  x\n</div></pre>\n</div>",
+      ),
+      ("<div></div>", "<div></div>"),
+      (
+        "<div>\u{FDD0}<div>a < b</div>\u{FDD1}<p>c</p></div>",
+        "<div><div>a < b</div>\n  <p>c</p>\n</div>",
+      ),
+    ];
+    for (input, expected) in cases {
+      expect_eq!(prettify(input), expected, from: input);
+    }
+  }
+}
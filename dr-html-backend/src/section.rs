@@ -1,6 +1,7 @@
 use crate::internal::*;
 
-pub fn number_prefix(level: u8, sect_nums: &mut [u16; 5]) -> String {
+/// numbers subsections of an appendix after its letter, e.g. `A.1.`
+pub fn number_prefix(level: u8, sect_nums: &mut [u16; 5], appendix: Option<&str>) -> String {
   debug_assert!(level > 0 && level < 6);
   let level_idx = (level - 1) as usize;
   sect_nums[level_idx] += 1;
@@ -11,6 +12,11 @@ pub fn number_prefix(level: u8, sect_nums: &mut [u16; 5]) -> String {
     .for_each(|n| *n = 0);
   let mut out = String::with_capacity(10);
   let mut idx = 0;
+  if let Some(letter) = appendix.filter(|_| level_idx > 0) {
+    out.push_str(letter);
+    out.push('.');
+    idx = 1;
+  }
   while idx <= level_idx {
     out.push_str(&sect_nums[idx].to_string());
     out.push('.');
@@ -20,6 +26,19 @@ pub fn number_prefix(level: u8, sect_nums: &mut [u16; 5]) -> String {
   out
 }
 
+/// zero-based appendix number to letters: `A`..`Z`, then `AA`, `AB`, etc.
+pub fn appendix_letter(mut num: usize) -> String {
+  let mut letters = Vec::with_capacity(2);
+  loop {
+    letters.push(b'A' + (num % 26) as u8);
+    if num < 26 {
+      break;
+    }
+    num = num / 26 - 1;
+  }
+  letters.iter().rev().map(|&b| b as char).collect()
+}
+
 pub fn class(section: &Section) -> &'static str {
   match section.level {
     1 => "sect1",
@@ -34,21 +53,80 @@ pub fn class(section: &Section) -> &'static str {
 
 impl AsciidoctorHtml {
   pub(super) fn should_number_section(&self, section: &Section) -> bool {
+    let special = section
+      .meta
+      .attrs
+      .as_ref()
+      .and_then(|a| a.str_positional_at(0));
+    if section
+      .meta
+      .attrs
+      .as_ref()
+      .is_some_and(|a| a.has_option("unnumbered"))
+    {
+      return false;
+    }
+    self.should_number(section.level, special)
+  }
+
+  /// `Appendix A: ` style prefix for `[appendix]` sections, appendices
+  /// are lettered in place of being numbered, unless `%unnumbered`
+  pub(super) fn appendix_caption(&mut self, section: &Section) -> Option<String> {
+    if section.level == 1 {
+      self.appendix_letter = None;
+    }
+    let attrs = section.meta.attrs.as_ref()?;
+    if attrs.str_positional_at(0) != Some("appendix") || attrs.has_option("unnumbered") {
+      return None;
+    }
+    let caption = self.appendix_prefix(self.appendix_num)?;
+    self.appendix_letter = Some(appendix_letter(self.appendix_num));
+    self.section_nums[1..].fill(0);
+    self.appendix_num += 1;
+    Some(caption)
+  }
+
+  /// same as `appendix_caption`, but for toc entries, using separate counter
+  pub(super) fn toc_appendix_caption(&mut self, node: &TocNode) -> Option<String> {
+    if node.level == 1 {
+      self.toc_appendix_letter = None;
+    }
+    if node.special_sect.as_deref() != Some("appendix") || node.unnumbered {
+      return None;
+    }
+    let caption = self.appendix_prefix(self.toc_appendix_num)?;
+    self.toc_appendix_letter = Some(appendix_letter(self.toc_appendix_num));
+    self.toc_section_nums[1..].fill(0);
+    self.toc_appendix_num += 1;
+    Some(caption)
+  }
+
+  fn appendix_prefix(&self, num: usize) -> Option<String> {
+    if !self
+      .doc_meta
+      .get_doctype()
+      .supports_special_section("appendix")
+    {
+      return None;
+    }
+    let letter = appendix_letter(num);
+    Some(match self.doc_meta.str("appendix-caption") {
+      Some(caption) => format!("{caption} {letter}: "),
+      None => format!("{letter}. "),
+    })
+  }
+
+  pub(super) fn should_number(&self, level: u8, special: Option<&str>) -> bool {
     let Some(sectnums) = self.doc_meta.get("sectnums") else {
       return false;
     };
-    if self.section_num_levels < section.level as isize {
+    if self.section_num_levels < level as isize || special == Some("appendix") {
       return false;
     }
     match sectnums {
       AttrValue::String(val) if val == "all" => true,
       AttrValue::Bool(true) => {
-        if let Some(special) = section
-          .meta
-          .attrs
-          .as_ref()
-          .and_then(|a| a.str_positional_at(0))
-        {
+        if let Some(special) = special {
           self
             .doc_meta
             .get_doctype()
@@ -80,8 +158,38 @@ mod tests {
       (3, [2, 4, 0, 0, 0], "2.4.1. ", [2, 4, 1, 0, 0]),
     ];
     for (level, mut sect_nums, expected, after_mutation) in cases {
-      expect_eq!(number_prefix(level, &mut sect_nums), expected.to_string());
+      expect_eq!(
+        number_prefix(level, &mut sect_nums, None),
+        expected.to_string()
+      );
       expect_eq!(sect_nums, after_mutation);
     }
+    let mut sect_nums = [2, 0, 0, 0, 0];
+    expect_eq!(
+      number_prefix(2, &mut sect_nums, Some("A")),
+      "A.1. ".to_string()
+    );
+    expect_eq!(
+      number_prefix(3, &mut sect_nums, Some("A")),
+      "A.1.1. ".to_string()
+    );
+    expect_eq!(sect_nums, [2, 1, 1, 0, 0]);
+  }
+
+  #[test]
+  fn test_appendix_letter() {
+    let cases = vec![
+      (0, "A"),
+      (25, "Z"),
+      (26, "AA"),
+      (27, "AB"),
+      (51, "AZ"),
+      (52, "BA"),
+      (701, "ZZ"),
+      (702, "AAA"),
+    ];
+    for (num, expected) in cases {
+      expect_eq!(appendix_letter(num), expected.to_string());
+    }
   }
 }
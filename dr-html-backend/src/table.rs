@@ -6,13 +6,15 @@ impl AsciidoctorHtml {
     let mut tag = OpenTag::new("table", block.meta.attrs.as_ref());
     tag.push_class("tableblock");
 
-    tag.push_resolved_attr_class(
-      "frame",
-      Some("all"),
-      Some("table-frame"),
+    // NB: `topbot` is the legacy name for `ends`
+    let frame = block
+      .meta
+      .attr_named("frame")
+      .or_else(|| self.doc_meta.str("table-frame"))
+      .unwrap_or("all");
+    tag.push_prefixed_class(
+      if frame == "topbot" { "ends" } else { frame },
       Some("frame-"),
-      &block.meta,
-      &self.doc_meta,
     );
 
     tag.push_resolved_attr_class(
@@ -24,14 +26,16 @@ impl AsciidoctorHtml {
       &self.doc_meta,
     );
 
-    let explicit_width = block
+    let width_attr = block
       .meta
       .attr_named("width")
       .map(|width| width.strip_suffix('%').unwrap_or(width))
-      .and_then(|width| width.parse::<u8>().ok())
-      .filter(|width| *width != 100);
+      .and_then(|width| width.parse::<u8>().ok());
+    let explicit_width = width_attr.filter(|width| *width != 100);
 
-    if block.meta.has_attr_option("autowidth") {
+    // NB: an explicit width still constrains an autowidth table, as in asciidoctor,
+    // autowidth then only governs column distribution (no `<col>` widths)
+    if block.meta.has_attr_option("autowidth") && width_attr.is_none() {
       tag.push_class("fit-content");
     } else if explicit_width.is_none() {
       tag.push_class("stretch");
@@ -54,6 +58,12 @@ impl AsciidoctorHtml {
       tag.push_style(format!("width: {}%;", width));
     }
 
+    if block.meta.has_attr_option("unbreakable") {
+      tag.push_style("page-break-inside: avoid;");
+    } else if block.meta.has_attr_option("breakable") {
+      tag.push_style("page-break-inside: auto;");
+    }
+
     self.push_open_tag(tag);
   }
 
@@ -62,9 +72,10 @@ impl AsciidoctorHtml {
       self.push_str(r#"<caption class="title">"#);
       if let Some(caption) = block.meta.attr_named("caption") {
         self.push_str(caption);
-      } else if !self.doc_meta.is_false("table-caption") {
-        self.table_caption_num += 1;
-        self.push(["Table ", &num_str!(self.table_caption_num), ". "]);
+      } else if let Some(num) = block.meta.numeral {
+        let caption = self.doc_meta.str_or("table-caption", "Table");
+        let prefix = format!("{caption} {num}. ");
+        self.push_str(&prefix);
       }
       let title = std::mem::take(&mut self.alt_html);
       self.push([&title, "</caption>"]);
@@ -1,5 +1,8 @@
-use asciidork_dr_html_backend::AsciidoctorHtml;
+use std::path::PathBuf;
+
+use asciidork_dr_html_backend::{convert_fragment, render_block, AsciidoctorHtml};
 use asciidork_eval::eval;
+use asciidork_meta::{DocType, JobAttr, JobSettings};
 use asciidork_parser::prelude::*;
 use test_utils::*;
 
@@ -22,6 +25,12 @@ assert_inline_html!(
 
 assert_inline_html!(passthrough, "+_<foo>&_+", r#"_&lt;foo&gt;&amp;_"#);
 assert_inline_html!(text_span, "[.foo]#bar#", r#"<span class="foo">bar</span>"#);
+
+assert_inline_html!(
+  unconstrained_text_span,
+  "[.yellow]##high##light and #mark#",
+  r#"<span class="yellow">high</span>light and <mark>mark</mark>"#
+);
 assert_inline_html!(passthrough_block, "[pass]\n_<foo>&_", "_<foo>&_");
 assert_inline_html!(highlight, "foo #bar#", r#"foo <mark>bar</mark>"#);
 assert_inline_html!(mono, "foo `bar`", r#"foo <code>bar</code>"#);
@@ -48,11 +57,69 @@ assert_inline_html!(
 );
 
 assert_inline_html!(
+  inline_doctype_strong,
+  "*bold* text",
+  r#"<strong>bold</strong> text"#
+);
+
+assert_html!(
+  inline_doctype_from_header_attr,
+  adoc! {"
+    = Document Title
+    :doctype: inline
+
+    *bold* text
+
+    second paragraph ignored
+  "},
+  r#"<strong>bold</strong> text"#
+);
+
+assert_html!(
   btn_macro,
+  |s: &mut JobSettings| {
+    s.doctype = Some(DocType::Inline);
+    s.job_attrs
+      .insert_unchecked("experimental", JobAttr::readonly(true));
+  },
   "press the btn:[OK] button",
   r#"press the <b class="button">OK</b> button"#
 );
 
+assert_html!(
+  btn_macro_escaped,
+  adoc! {"
+    :experimental:
+
+    btn:[Save & Exit]
+  "},
+  html! {r#"
+    <div class="paragraph"><p><b class="button">Save &amp; Exit</b></p></div>
+  "#}
+);
+
+assert_html!(
+  ui_macros_escaped,
+  adoc! {"
+    :experimental:
+
+    kbd:[Ctrl+<] kbd:[&] menu:File[Save & Exit > Now & Then] btn:[<OK>]
+  "},
+  html! {r#"
+    <div class="paragraph">
+      <p><span class="keyseq"><kbd>Ctrl</kbd>+<kbd>&lt;</kbd></span> <kbd>&amp;</kbd> <span class="menuseq"><span class="menu">File</span>&#160;&#9656;<span class="submenu">Save &amp; Exit</span>&#160;&#9656;<span class="menuitem">Now &amp; Then</span></span> <b class="button">&lt;OK&gt;</b></p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  btn_macro_requires_experimental,
+  "btn:[Save & Exit]",
+  html! {r#"
+    <div class="paragraph"><p>btn:[Save &amp; Exit]</p></div>
+  "#}
+);
+
 assert_html!(
   comment_lines,
   adoc! {r#"
@@ -106,6 +173,20 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  menu_macro_no_items,
+  adoc! {r#"
+    :experimental:
+
+    select menu:File[].
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>select <b class="menuref">File</b>.</p>
+    </div>
+  "#}
+);
+
 assert_html!(
   menu_macro_2,
   "select menu:File[Save > Reset].",
@@ -461,6 +542,85 @@ assert_html!(
   "##}
 );
 
+assert_html!(
+  footnote_backlink_and_role,
+  adoc! {r#"
+    :footnote-backlink: &#8593;
+    :footnote-role: theme-note
+
+    foo.footnote:x[bar]
+
+    baz.footnote:x[]
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>foo.
+        <sup class="footnote theme-note" id="_footnote_x">
+          [<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]
+        </sup>
+      </p>
+    </div>
+    <div class="paragraph">
+      <p>baz.
+        <sup class="footnoteref theme-note">
+          [<a class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]
+        </sup>
+      </p>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1">
+        <a href="#_footnoteref_1" class="footnote-backlink">&#8593;</a> bar
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  footnote_backlink_and_role_escaped,
+  adoc! {r#"
+    :footnote-backlink: <&#8593; & back>
+    :footnote-role: x" onclick="y
+
+    foo.footnote:[bar]
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>foo.
+        <sup class="footnote x&quot; onclick=&quot;y">
+          [<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]
+        </sup>
+      </p>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1">
+        <a href="#_footnoteref_1" class="footnote-backlink">&lt;&#8593; &amp; back&gt;</a> bar
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  footnotes_title,
+  adoc! {r#"
+    :footnotes-title: Notes
+
+    foo.footnote:[bar]
+  "#},
+  contains: r#"<div id="footnotes"><hr><div class="title">Notes</div><div class="footnote""#
+);
+
+assert_html!(
+  footnotes_title_escaped,
+  adoc! {r#"
+    :footnotes-title: <Notes> & Refs
+
+    foo.footnote:[bar]
+  "#},
+  contains: r#"<div class="title">&lt;Notes&gt; &amp; Refs</div>"#
+);
+
 assert_html!(
   two_footnotes_w_cust,
   adoc! {r#"
@@ -495,6 +655,73 @@ assert_html!(
   "##}
 );
 
+assert_html!(
+  footnote_forward_and_backward_refs,
+  adoc! {r#"
+    a.footnote:later[]
+
+    b.footnote:[anon]
+
+    c.footnote:later[Later text]
+
+    d.footnote:later[]
+
+    e.footnote:nope[]
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>a.
+        <sup class="footnoteref">
+          [<a class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]
+        </sup>
+      </p>
+    </div>
+    <div class="paragraph">
+      <p>b.
+        <sup class="footnote">
+          [<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]
+        </sup>
+      </p>
+    </div>
+    <div class="paragraph">
+      <p>c.
+        <sup class="footnote" id="_footnote_later">
+          [<a id="_footnoteref_2" class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]
+        </sup>
+      </p>
+    </div>
+    <div class="paragraph">
+      <p>d.
+        <sup class="footnoteref">
+          [<a class="footnote" href="#_footnotedef_2" title="View footnote.">2</a>]
+        </sup>
+      </p>
+    </div>
+    <div class="paragraph">
+      <p>e.
+        <sup class="footnoteref red" title="Unresolved footnote reference.">[nope]</sup>
+      </p>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1">
+        <a href="#_footnoteref_1">1</a>. anon
+      </div>
+      <div class="footnote" id="_footnotedef_2">
+        <a href="#_footnoteref_2">2</a>. Later text
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  missing_footnote_reference_escaped,
+  adoc! {r#"
+    a.footnote:x&y[]
+  "#},
+  contains: r#"<sup class="footnoteref red" title="Unresolved footnote reference.">[x&amp;y]</sup>"#
+);
+
 assert_html!(
   quote_newlines,
   adoc! {r#"
@@ -551,6 +778,47 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  hardbreaks_option_toggled_mid_document,
+  adoc! {r#"
+    = Document Title
+    :hardbreaks-option:
+
+    header
+    set
+
+    :!hardbreaks-option:
+
+    not
+    broken
+
+    :hardbreaks-option:
+
+    ----
+    listing
+    ----
+
+    broken
+    again
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>header<br> set</p>
+    </div>
+    <div class="paragraph">
+      <p>not broken</p>
+    </div>
+    <div class="listingblock">
+      <div class="content">
+        <pre>listing</pre>
+      </div>
+    </div>
+    <div class="paragraph">
+      <p>broken<br> again</p>
+    </div>
+  "#}
+);
+
 assert_html!(
   simple_listing_block,
   adoc! {r#"
@@ -629,6 +897,48 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  admonition_font_icons_w_id_role_and_caption,
+  adoc! {r#"
+    :icons: font
+    :warning-caption: Careful
+
+    [NOTE.highlight#n1]
+    Heads up.
+
+    [TIP,caption=Pro Tip]
+    Try this.
+
+    WARNING: Hot.
+  "#},
+  html! {r#"
+    <div id="n1" class="admonitionblock note highlight">
+      <table>
+        <tr>
+          <td class="icon"><i class="fa icon-note" title="Note"></i></td>
+          <td class="content">Heads up.</td>
+        </tr>
+      </table>
+    </div>
+    <div class="admonitionblock tip">
+      <table>
+        <tr>
+          <td class="icon"><i class="fa icon-tip" title="Pro Tip"></i></td>
+          <td class="content">Try this.</td>
+        </tr>
+      </table>
+    </div>
+    <div class="admonitionblock warning">
+      <table>
+        <tr>
+          <td class="icon"><i class="fa icon-warning" title="Careful"></i></td>
+          <td class="content">Hot.</td>
+        </tr>
+      </table>
+    </div>
+  "#}
+);
+
 assert_html!(
   escaped_ifdef,
   adoc! {"
@@ -701,6 +1011,16 @@ fn test_head_opts() {
       Contains("<title>Such Custom Title</title>"),
     ),
     (":lang: es", Contains("lang=\"es\"")),
+    (":lang: es", DoesNotContain(" dir=")),
+    (":dir: rtl", Contains(r#"<html lang="en" dir="rtl">"#)),
+    (":dir: Auto", Contains(r#"<html lang="en" dir="auto">"#)),
+    (r#":dir: rtl" onload="x"#, DoesNotContain(" dir=")),
+    (":lang: ar", Contains(r#"<html lang="ar" dir="rtl">"#)),
+    (":lang: he-IL", Contains(r#"<html lang="he-IL" dir="rtl">"#)),
+    (
+      ":lang: ar\n:dir: ltr",
+      Contains(r#"<html lang="ar" dir="ltr">"#),
+    ),
     (":encoding: latin1", Contains("charset=\"latin1\"")),
     (":reproducible:", DoesNotContain("generator")),
     (
@@ -770,6 +1090,193 @@ fn test_head_opts() {
   assert!(html.contains("<title>Untitled</title>"));
 }
 
+#[test]
+fn test_head_title_plain_text_fallbacks() {
+  let input = "= The image:logo.png[Company Logo] stem:[x < 2] Guide\n\nbody\n";
+  let parser = test_parser!(input);
+  let document = parser.parse().unwrap().document;
+  let html = eval(&document, AsciidoctorHtml::new()).unwrap();
+  assert!(html.contains("<title>The Company Logo x &lt; 2 Guide</title>"));
+}
+
+#[test]
+fn color_role_text_spans() {
+  let colors = [
+    "aqua", "black", "blue", "fuchsia", "gray", "green", "lime", "maroon", "navy", "olive",
+    "purple", "red", "silver", "teal", "white", "yellow",
+  ];
+  for color in colors {
+    let input =
+      format!("[.{color}]#fg# [.{color}-background]#bg# [.{color}.{color}-background]##both##");
+    let parser = test_parser!(&input);
+    let document = parser.parse().unwrap().document;
+    let html = eval(&document, AsciidoctorHtml::new()).unwrap();
+    let expected = format!(
+      r#"<p><span class="{color}">fg</span> <span class="{color}-background">bg</span> <span class="{color} {color}-background">both</span></p>"#
+    );
+    assert!(
+      html.contains(&expected),
+      "\n`{}` was NOT found when expected\n\n```html\n{}\n```",
+      expected,
+      html.replace('>', ">\n").trim()
+    );
+  }
+}
+
+#[test]
+fn convert_fragment_w_assets() {
+  let input = adoc! {r#"
+    = Document Title
+    :imagesdir: img
+
+    image::diagram.png[]
+
+    An image:icon.png[] and image:https://example.com/remote.png[] inline.
+
+    |===
+    a|image::diagram.png[]
+
+    See image:cell.png[].
+    |===
+
+    image::chart.svg[opts=interactive,fallback=chart.png]
+  "#};
+  let parser = test_parser!(input);
+  let result = parser.parse().unwrap();
+  let assets = result.assets();
+  let fragment = convert_fragment(result.document, assets).unwrap();
+  assert!(fragment.html.starts_with(r#"<div class="imageblock">"#));
+  assert!(fragment
+    .html
+    .contains(r#"<img src="img/icon.png" alt="icon">"#));
+  expect_eq!(
+    fragment.assets,
+    vec![
+      PathBuf::from("img/diagram.png"),
+      PathBuf::from("img/icon.png"),
+      PathBuf::from("img/cell.png"),
+      PathBuf::from("img/chart.svg"),
+      PathBuf::from("img/chart.png"),
+    ]
+  );
+}
+
+#[test]
+fn render_single_block() {
+  let input = adoc! {r#"
+    = Document Title
+    :table-caption!:
+
+    intro
+
+    .Data
+    |===
+    |a |b
+    |===
+  "#};
+  let parser = test_parser!(input);
+  let document = parser.parse().unwrap().document;
+  let asciidork_ast::DocContent::Blocks(blocks) = &document.content else {
+    panic!("expected blocks");
+  };
+  expect_eq!(
+    render_block(&blocks[1], &document),
+    html! {r#"
+      <table class="tableblock frame-all grid-all stretch">
+        <caption class="title">Data</caption>
+        <colgroup><col style="width: 50%;"><col style="width: 50%;"></colgroup>
+        <tbody>
+          <tr>
+            <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+            <td class="tableblock halign-left valign-top"><p class="tableblock">b</p></td>
+          </tr>
+        </tbody>
+      </table>
+    "#}
+  );
+}
+
+#[test]
+fn render_block_resolves_xrefs_against_document() {
+  let input = adoc! {r#"
+    = Document Title
+
+    == Setup
+
+    See <<_setup>> and <<tgt>>.
+
+    [#tgt]
+    == Target
+  "#};
+  let parser = test_parser!(input);
+  let document = parser.parse().unwrap().document;
+  let asciidork_ast::DocContent::Sectioned { sections, .. } = &document.content else {
+    panic!("expected sections");
+  };
+  expect_eq!(
+    render_block(&sections[0].blocks[0], &document),
+    html! {r##"
+      <div class="paragraph">
+        <p>See <a href="#_setup">Setup</a> and <a href="#tgt">Target</a>.</p>
+      </div>
+    "##}
+  );
+}
+
+#[test]
+fn eval_to_writer_matches_string_output() {
+  #[derive(Default)]
+  struct CountingWriter {
+    bytes: Vec<u8>,
+    writes: usize,
+  }
+
+  impl std::io::Write for CountingWriter {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+      self.writes += 1;
+      self.bytes.extend_from_slice(buf);
+      Ok(buf.len())
+    }
+    fn flush(&mut self) -> std::io::Result<()> {
+      Ok(())
+    }
+  }
+
+  let chunk = adoc! {r#"
+    Some _text_ with a footnote.footnote:[Footnote text.]
+
+    .Listing
+    [source,rust]
+    ----
+    fn main() {} // <1>
+    ----
+    <1> the entry point
+
+    [cols="1,2"]
+    |===
+    | a | b
+    |===
+
+    * one
+    ** two
+
+  "#};
+  let mut input = String::from("= Big Document\n:toc:\n\npreamble\n\n");
+  for section in 1..=10 {
+    input.push_str(&format!("== Section {section}\n\n"));
+    while input.len() < section * 300_000 {
+      input.push_str(chunk);
+    }
+  }
+  let document = test_parser!(&input).parse().unwrap().document;
+  let expected = eval(&document, AsciidoctorHtml::new()).unwrap();
+  let mut writer = CountingWriter::default();
+  asciidork_eval::eval_to_writer(&document, AsciidoctorHtml::new(), &mut writer).unwrap();
+  assert!(input.len() > 2_000_000);
+  assert!(writer.writes > 1);
+  assert!(String::from_utf8(writer.bytes).unwrap() == expected);
+}
+
 #[test]
 fn test_non_embedded() {
   let input = adoc! {r#"
@@ -817,3 +1324,201 @@ fn test_non_embedded() {
     from: input
   );
 }
+
+#[test]
+fn test_css_image_dimensions() {
+  let input = adoc! {r#"
+    image::tiger.png[Tiger,300]
+
+    foo image:linux.png[Linux,width=50%,height=150] bar
+  "#};
+  let expected = html! {r#"
+    <div class="imageblock">
+      <div class="content">
+        <img src="tiger.png" alt="Tiger" style="width:300px">
+      </div>
+    </div>
+    <div class="paragraph">
+      <p>foo <span class="image"><img src="linux.png" alt="Linux" style="width:50%;height:150px"></span> bar</p>
+    </div>
+  "#};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::embedded());
+  let doc = parser.parse().unwrap().document;
+  expect_eq!(
+    eval(&doc, AsciidoctorHtml::new().with_css_image_dimensions()).unwrap(),
+    expected,
+    from: input
+  );
+}
+
+#[derive(Debug)]
+struct UpperHighlighter;
+
+impl asciidork_dr_html_backend::Highlighter for UpperHighlighter {
+  fn highlight(&self, _lang: &str, code: &str) -> String {
+    code.to_uppercase()
+  }
+}
+
+#[test]
+fn test_chained_settings_propagate_to_asciidoc_cells() {
+  let input = adoc! {r#"
+    |===
+    a|
+    image::tiger.png[Tiger,300]
+
+    [source,ruby]
+    ----
+    puts 1
+    ----
+    |===
+  "#};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::embedded());
+  let doc = parser.parse().unwrap().document;
+  let backend = AsciidoctorHtml::new()
+    .with_css_image_dimensions()
+    .with_highlighter(Box::new(UpperHighlighter));
+  let html = eval(&doc, backend).unwrap();
+  assert!(html.contains(r#"style="width:300px""#), "{html}");
+  assert!(html.contains("PUTS 1"), "{html}");
+}
+
+#[test]
+fn test_pretty_output() {
+  let input = adoc! {r#"
+    para _one_
+
+    ====
+    ----
+    <div>
+      indented
+    </div>
+    ----
+    ====
+  "#};
+  let expected = indoc::indoc! {r#"
+    <div class="paragraph">
+      <p>para <em>one</em></p>
+    </div>
+    <div class="exampleblock">
+      <div class="content">
+        <div class="listingblock">
+          <div class="content">
+            <pre>&lt;div&gt;
+      indented
+    &lt;/div&gt;</pre>
+          </div>
+        </div>
+      </div>
+    </div>"#};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::embedded());
+  let doc = parser.parse().unwrap().document;
+  expect_eq!(
+    eval(&doc, AsciidoctorHtml::new().pretty()).unwrap(),
+    expected,
+    from: input
+  );
+}
+
+#[test]
+fn test_pretty_output_leaves_passthroughs_alone() {
+  let input = adoc! {r#"
+    ++++
+    <div><p>1 < 2</p></div>
+    ++++
+
+    a pass:[<span>3 < 4</span>] b
+
+    [cols=1a]
+    |===
+    |+++<div>5 < 6</div>+++
+    |===
+  "#};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::embedded());
+  let doc = parser.parse().unwrap().document;
+  let html = eval(&doc, AsciidoctorHtml::new().pretty()).unwrap();
+  assert!(html.starts_with("<div><p>1 < 2</p></div>\n"), "{html}");
+  assert!(html.contains("<p>a <span>3 < 4</span> b</p>"), "{html}");
+  assert!(html.contains("<div>5 < 6</div>"), "{html}");
+  assert!(!html.contains(['\u{FDD0}', '\u{FDD1}']), "{html}");
+}
+
+assert_html!(
+  ifdef_gated_paragraphs,
+  adoc! {r#"
+    :flag:
+    :off!:
+
+    ifdef::flag[]
+    flag is set
+    endif::[]
+
+    ifndef::flag[]
+    flag is not set
+    endif::[]
+
+    ifdef::off,flag[any matched]
+
+    ifdef::off+flag[all matched]
+
+    ifndef::off[]
+    off is unset
+    endif::[]
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>flag is set</p></div>
+    <div class="paragraph"><p>any matched</p></div>
+    <div class="paragraph"><p>off is unset</p></div>
+  "#}
+);
+
+assert_html!(
+  ifeval_gated_blocks,
+  adoc! {r#"
+    :version: 2
+    :env: prod
+
+    ifeval::["{version}" >= "2"]
+    ====
+    version two or later
+    ====
+    endif::[]
+
+    ifeval::[{version} > 3]
+    version above three
+    endif::[]
+
+    ifeval::["{env}" == "prod"]
+    in production
+    endif::[]
+  "#},
+  html! {r#"
+    <div class="exampleblock">
+      <div class="content">
+        <div class="paragraph"><p>version two or later</p></div>
+      </div>
+    </div>
+    <div class="paragraph"><p>in production</p></div>
+  "#}
+);
+
+assert_html!(
+  attr_decls_between_paragraphs,
+  adoc! {r#"
+    para one
+
+    :foo: bar
+    :baz: qux
+    :!nope:
+
+    para two {foo}
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>para one</p></div>
+    <div class="paragraph"><p>para two bar</p></div>
+  "#}
+);
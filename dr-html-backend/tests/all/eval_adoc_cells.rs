@@ -465,3 +465,17 @@ assert_html!(
   "#},
   contains: r#"<td class="icon"><div class="title">Note</div></td>"#
 );
+
+assert_html!(
+  adoc_cell_inherits_body_imagesdir,
+  adoc! {r#"
+    foo
+
+    :imagesdir: imgs
+
+    |===
+    a|image:foo.png[]
+    |===
+  "#},
+  contains: r#"<img src="imgs/foo.png" alt="foo">"#
+);
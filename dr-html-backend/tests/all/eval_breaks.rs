@@ -59,3 +59,23 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  ignored_block_titles_dont_leak,
+  adoc! {r#"
+    .Break
+    '''
+
+    .Pass
+    ++++
+    <hr>
+    ++++
+
+    para
+  "#},
+  html! {r#"
+    <hr>
+    <hr>
+    <div class="paragraph"><p>para</p></div>
+  "#}
+);
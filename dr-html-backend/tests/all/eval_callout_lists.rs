@@ -201,6 +201,18 @@ assert_html!(
   )
 );
 
+assert_html!(
+  callout_list_title,
+  adoc! {r#"
+    ----
+    foo <1>
+    ----
+    .Notes
+    <1> the foo
+  "#},
+  contains: r#"<div class="colist arabic"><div class="title">Notes</div><ol>"#
+);
+
 // helpers
 
 fn wrap_listing(inner: &str) -> String {
@@ -212,7 +224,7 @@ fn wrap_listing(inner: &str) -> String {
 
 fn wrap_source_appending(lang: &str, inner: &str, rest: String) -> String {
   let listing = wrap_listing(&format!(
-    r#"<pre class="highlight"><code class="language-{lang}" data-lang="{lang}">{}</code></pre>"#,
+    r#"<pre class="highlight"><code class="language-{lang}" data-lang="{lang}" translate="no">{}</code></pre>"#,
     inner.trim(),
   ));
   format!("{listing}{rest}")
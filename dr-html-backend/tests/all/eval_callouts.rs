@@ -99,7 +99,7 @@ fn wrap_listing(inner: &str) -> String {
 
 fn wrap_source(lang: &str, inner: &str) -> String {
   wrap_listing(&format!(
-    r#"<pre class="highlight"><code class="language-{lang}" data-lang="{lang}">{}</code></pre>"#,
+    r#"<pre class="highlight"><code class="language-{lang}" data-lang="{lang}" translate="no">{}</code></pre>"#,
     inner.trim(),
   ))
 }
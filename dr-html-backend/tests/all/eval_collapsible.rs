@@ -52,3 +52,21 @@ assert_html!(
     </details>
   "#}
 );
+
+assert_html!(
+  collapsible_open_w_id_and_role,
+  adoc! {r#"
+    [#details-1%collapsible%open.role]
+    ====
+    inner content
+    ====
+  "#},
+  html! {r#"
+    <details id="details-1" open class="role">
+      <summary class="title">Details</summary>
+      <div class="content">
+        <div class="paragraph"><p>inner content</p></div>
+      </div>
+    </details>
+  "#}
+);
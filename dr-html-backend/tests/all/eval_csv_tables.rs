@@ -195,6 +195,61 @@ assert_html!(
     r#"<p class="tableblock">2</p>"#,
 );
 
+assert_html!(
+  csv_shorthand_and_tsv_header_rows,
+  adoc! {"
+    [%header]
+    ,===
+    Name,Age
+    Bob,3
+    ,===
+
+    [%header,format=tsv]
+    |===
+    A\tB
+    c\td
+    |===
+  "},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 50%;">
+        <col style="width: 50%;">
+      </colgroup>
+      <thead>
+        <tr>
+          <th class="tableblock halign-left valign-top">Name</th>
+          <th class="tableblock halign-left valign-top">Age</th>
+        </tr>
+      </thead>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">Bob</p></td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">3</p></td>
+        </tr>
+      </tbody>
+    </table>
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 50%;">
+        <col style="width: 50%;">
+      </colgroup>
+      <thead>
+        <tr>
+          <th class="tableblock halign-left valign-top">A</th>
+          <th class="tableblock halign-left valign-top">B</th>
+        </tr>
+      </thead>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">c</p></td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">d</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
 assert_html!(
   complex_csv,
   adoc! {r#"
@@ -34,6 +34,22 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  listing_block_preserves_backslashes,
+  adoc! {r#"
+    ----
+    C:\dir \*foo* \{bar}
+    ----
+  "#},
+  html! {r#"
+    <div class="listingblock">
+      <div class="content">
+        <pre>C:\dir \*foo* \{bar}</pre>
+      </div>
+    </div>
+  "#}
+);
+
 assert_html!(
   passthrough_block,
   adoc! {r#"
@@ -60,6 +76,49 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  passthrough_block_macro,
+  adoc! {r#"
+    pass::[<video src="a&b.mp4"></video>]
+
+    [subs=normal]
+    pass::[<b>_x_ [y]</b>]
+
+    after
+  "#},
+  html! {r#"
+    <video src="a&b.mp4"></video>
+    &lt;b&gt;<em>x</em> [y]&lt;/b&gt;
+    <div class="paragraph"><p>after</p></div>
+  "#}
+);
+
+assert_html!(
+  subs_none_blocks_emit_raw_content,
+  adoc! {r#"
+    :foo: bar
+
+    [subs=none]
+    ....
+    <tag> & {foo} \*bar* +++baz+++ <1>
+    ....
+
+    [subs=none]
+    <tag> & <<id>> pass:[x] +
+    `mono` _em_
+  "#},
+  html! {r#"
+    <div class="literalblock">
+      <div class="content">
+        <pre><tag> & {foo} \*bar* +++baz+++ <1></pre>
+      </div>
+    </div>
+    <div class="paragraph">
+      <p><tag> & <<id>> pass:[x] + `mono` _em_</p>
+    </div>
+  "#}
+);
+
 assert_html!(
   example_block,
   adoc! {r#"
@@ -129,6 +188,35 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  titled_sidebars,
+  adoc! {r#"
+    [#side.aside]
+    .Sidebar *Title*
+    ****
+    foo
+    ****
+
+    .Short
+    [sidebar]
+    bar
+  "#},
+  html! {r#"
+    <div id="side" class="sidebarblock aside">
+      <div class="content">
+        <div class="title">Sidebar <strong>Title</strong></div>
+        <div class="paragraph"><p>foo</p></div>
+      </div>
+    </div>
+    <div class="sidebarblock">
+      <div class="content">
+        <div class="title">Short</div>
+        bar
+      </div>
+    </div>
+  "#}
+);
+
 assert_html!(
   basic_block_example,
   adoc! {r#"
@@ -158,3 +246,96 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  titled_listing_literal_and_open_blocks,
+  adoc! {r#"
+    .Code
+    ----
+    code
+    ----
+
+    .Output
+    ....
+    out
+    ....
+
+    .Aside
+    --
+    open
+    --
+  "#},
+  html! {r#"
+    <div class="listingblock">
+      <div class="title">Code</div>
+      <div class="content"><pre>code</pre></div>
+    </div>
+    <div class="literalblock">
+      <div class="title">Output</div>
+      <div class="content"><pre>out</pre></div>
+    </div>
+    <div class="openblock">
+      <div class="title">Aside</div>
+      <div class="content">
+        <div class="paragraph"><p>open</p></div>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  titled_example_blocks_numbered,
+  adoc! {r#"
+    .First
+    ====
+    foo
+    ====
+
+    ====
+    untitled
+    ====
+
+    [caption="Exhibit A: "]
+    .Custom
+    ====
+    bar
+    ====
+
+    .Second
+    ====
+    baz
+    ====
+  "#},
+  contains:
+    r#"<div class="title">Example 1. First</div>"#,
+    r#"<div class="title">Exhibit A: Custom</div>"#,
+    r#"<div class="title">Example 2. Second</div>"#,
+);
+
+assert_html!(
+  unrendered_block_titles_dont_leak,
+  adoc! {r#"
+    .Ignored
+    [discrete]
+    == Heading
+
+    .Also ignored
+    <<<
+
+    |===
+    |a
+    |===
+  "#},
+  html! {r#"
+    <h2 id="_heading" class="discrete">Heading</h2>
+    <div style="page-break-after: always;"></div>
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup><col style="width: 100%;"></colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
@@ -297,3 +297,65 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  qanda_dlist,
+  adoc! {r#"
+    .Q&A
+    [qanda]
+    What is it?:: A thing.
+    Why?::
+    * Because.
+  "#},
+  html! {r#"
+    <div class="qlist qanda">
+      <div class="title">Q&amp;A</div>
+      <ol>
+        <li>
+          <p><em>What is it?</em></p>
+          <p>A thing.</p>
+        </li>
+        <li>
+          <p><em>Why?</em></p>
+          <div class="ulist"><ul><li><p>Because.</p></li></ul></div>
+        </li>
+      </ol>
+    </div>
+  "#}
+);
+
+assert_html!(
+  horizontal_dlist,
+  adoc! {r#"
+    [horizontal,labelwidth=20%,itemwidth=80]
+    CPU:: The brain
+    RAM:: Memory
+  "#},
+  html! {r#"
+    <div class="hdlist">
+      <table>
+        <colgroup>
+          <col style="width: 20%;">
+          <col style="width: 80%;">
+        </colgroup>
+        <tr>
+          <td class="hdlist1">CPU</td>
+          <td class="hdlist2"><p>The brain</p></td>
+        </tr>
+        <tr>
+          <td class="hdlist1">RAM</td>
+          <td class="hdlist2"><p>Memory</p></td>
+        </tr>
+      </table>
+    </div>
+  "#}
+);
+
+assert_html!(
+  horizontal_dlist_non_numeric_widths_ignored,
+  adoc! {r#"
+    [horizontal,labelwidth="20%;color:red",itemwidth=75.5%]
+    CPU:: The brain
+  "#},
+  contains: r#"<colgroup><col><col style="width: 75.5%;"></colgroup>"#
+);
@@ -103,3 +103,16 @@ assert_html!(
     r#"<p class="tableblock">one</p>"#,
     r#"<p class="tableblock">two</p>"#,
 );
+
+assert_html!(
+  dsv_only_escapes_delimiter_and_backslash,
+  adoc! {r#"
+    [cols="l,1"]
+    :===
+    C\:\Users\bob:one\:two
+    :===
+  "#},
+  contains:
+    r#"<pre>C:\Users\bob</pre>"#,
+    r#"<p class="tableblock">one:two</p>"#,
+);
@@ -309,5 +309,52 @@ assert_html!(
   contains: "\u{00A0}<a href=\"http://asciidoc.org\">AsciiDoc</a> project page.</p>"
 );
 
+assert_html!(
+  mailto_macro_w_subject_and_body,
+  adoc! {r#"
+    mailto:join@discuss.example.org[Subscribe,Subscribe me,I want to join!]
+
+    mailto:me@example.com[] or mailto:me@example.com[Email _me_]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p><a href="mailto:join@discuss.example.org?subject=Subscribe%20me&amp;body=I%20want%20to%20join%21">Subscribe</a></p>
+    </div>
+    <div class="paragraph">
+      <p><a href="mailto:me@example.com">me@example.com</a> or <a href="mailto:me@example.com">Email <em>me</em></a></p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  mailto_macro_target_w_query,
+  adoc! {r#"
+    mailto:me@example.com?cc=you@example.com[Ask,Café?]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p><a href="mailto:me@example.com?cc=you@example.com&amp;subject=Caf%C3%A9%3F">Ask</a></p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  link_macro_adoc_targets_use_outfilesuffix,
+  adoc! {r#"
+    link:other.adoc[Other], link:guide/setup.adoc#install[Install], link:https://example.com/raw.adoc[raw] and link:notes.txt[notes]
+  "#},
+  contains: r##"<a href="other.html">Other</a>, <a href="guide/setup.html#install">Install</a>, <a href="https://example.com/raw.adoc">raw</a> and <a href="notes.txt">notes</a>"##
+);
+
+assert_html!(
+  link_macro_adoc_target_custom_outfilesuffix,
+  adoc! {r#"
+    :outfilesuffix: .htm
+
+    link:other.adoc[Other]
+  "#},
+  contains: r##"<a href="other.htm">Other</a>"##
+);
+
 // let string = r#"Hello\u{00A0}World"#;
 // println!("{}", string); // Output: Hello World (with no-break space)
@@ -1,4 +1,5 @@
 use asciidork_meta::JobSettings;
+use asciidork_parser::prelude::*;
 use test_utils::*;
 
 assert_html!(
@@ -114,6 +115,40 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  block_image_imagesdir_absolute_targets,
+  adoc! {r#"
+    :imagesdir: assets
+
+    image::tiger.png[Tiger]
+
+    image::https://x.com/tiger.png[Tiger]
+
+    image::data:image/gif;base64,R0lGODlhAQABAAAAACw=[Dot]
+
+    image::ftp://x.com/tiger.png[Tiger]
+
+    image::C:/images/tiger.png[Tiger]
+  "#},
+  html! {r#"
+    <div class="imageblock">
+      <div class="content"><img src="assets/tiger.png" alt="Tiger"></div>
+    </div>
+    <div class="imageblock">
+      <div class="content"><img src="https://x.com/tiger.png" alt="Tiger"></div>
+    </div>
+    <div class="imageblock">
+      <div class="content"><img src="data:image/gif;base64,R0lGODlhAQABAAAAACw=" alt="Dot"></div>
+    </div>
+    <div class="imageblock">
+      <div class="content"><img src="ftp://x.com/tiger.png" alt="Tiger"></div>
+    </div>
+    <div class="imageblock">
+      <div class="content"><img src="C:/images/tiger.png" alt="Tiger"></div>
+    </div>
+  "#}
+);
+
 // https://docs.asciidoctor.org/asciidoc/latest/macros/image-position/
 assert_html!(
   image_position_frame_attrs,
@@ -152,6 +187,31 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  image_align_attr_and_role_equivalent,
+  adoc! {r#"
+    [.text-center]
+    image::a.png[]
+
+    image::b.png[align=center]
+
+    [align=center]
+    image::c.png[]
+
+    [.text-center]
+    image::d.png[align=center]
+
+    image::e.png[role=text-center,align=center]
+  "#},
+  html! {r#"
+    <div class="imageblock text-center"><div class="content"><img src="a.png" alt="a"></div></div>
+    <div class="imageblock text-center"><div class="content"><img src="b.png" alt="b"></div></div>
+    <div class="imageblock text-center"><div class="content"><img src="c.png" alt="c"></div></div>
+    <div class="imageblock text-center"><div class="content"><img src="d.png" alt="d"></div></div>
+    <div class="imageblock text-center"><div class="content"><img src="e.png" alt="e"></div></div>
+  "#}
+);
+
 // https://docs.asciidoctor.org/asciidoc/latest/macros/image-link/
 assert_html!(
   image_links,
@@ -188,6 +248,39 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  image_links_w_roles,
+  adoc! {r#"
+    [.frame]
+    image::logo.png[Logo,link=https://example.org]
+
+    image::logo.png[Logo,link=https://example.org,role=frame]
+
+    image:apply.jpg[Apply,link=https://apply.example.org,role=frame] today!
+  "#},
+  html! {r#"
+    <div class="imageblock frame">
+      <div class="content">
+        <a class="image" href="https://example.org"><img src="logo.png" alt="Logo"></a>
+      </div>
+    </div>
+    <div class="imageblock frame">
+      <div class="content">
+        <a class="image" href="https://example.org"><img src="logo.png" alt="Logo"></a>
+      </div>
+    </div>
+    <div class="paragraph">
+      <p>
+        <span class="image frame">
+          <a class="image" href="https://apply.example.org">
+            <img src="apply.jpg" alt="Apply">
+          </a>
+        </span> today!
+      </p>
+    </div>
+  "#}
+);
+
 // https://docs.asciidoctor.org/asciidoc/latest/macros/image-position/
 assert_html!(
   image_size,
@@ -266,6 +359,97 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  data_uri_images,
+  adoc! {r#"
+    :data-uri:
+    :imagesdir: tests/fixtures
+
+    image::dot.png[Dot]
+
+    image:dot.svg[] image:missing.png[]
+  "#},
+  contains:
+    r#"<img src="data:image/png;base64,iVBORw0KGgo"#,
+    r#"<img src="data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciLz4K" alt="dot">"#,
+    r#"<img src="tests/fixtures/missing.png" alt="missing">"#,
+);
+
+assert_html!(
+  data_uri_images_secure,
+  |job_settings: &mut JobSettings| {
+    job_settings.safe_mode = asciidork_meta::SafeMode::Secure;
+  },
+  adoc! {r#"
+    :data-uri:
+    :imagesdir: tests/fixtures
+
+    image:dot.png[]
+  "#},
+  contains: r#"<img src="tests/fixtures/dot.png" alt="dot">"#
+);
+
+#[test]
+fn data_uri_custom_asset_loader() {
+  #[derive(Debug)]
+  struct Loader;
+  impl asciidork_dr_html_backend::AssetLoader for Loader {
+    fn load(&self, path: &str) -> Option<Vec<u8>> {
+      (path == "img/foo.gif").then(|| b"GIF".to_vec())
+    }
+  }
+  let bump = &Bump::new();
+  let mut settings = JobSettings::embedded();
+  settings.safe_mode = asciidork_meta::SafeMode::Safe;
+  let mut parser = Parser::from_str("image:img/foo.gif[]", SourceFile::Tmp, bump);
+  parser.apply_job_settings(settings);
+  let mut document = parser.parse().unwrap().document;
+  document.meta.insert_doc_attr("data-uri", true).unwrap();
+  let backend =
+    asciidork_dr_html_backend::AsciidoctorHtml::new().with_asset_loader(Box::new(Loader));
+  let html = asciidork_eval::eval(&document, backend).unwrap();
+  assert!(html.contains(r#"<img src="data:image/gif;base64,R0lG" alt="foo">"#));
+}
+
+#[test]
+fn data_uri_jailed_to_docdir() {
+  #[derive(Debug)]
+  struct Loader;
+  impl asciidork_dr_html_backend::AssetLoader for Loader {
+    fn load(&self, _path: &str) -> Option<Vec<u8>> {
+      Some(b"GIF".to_vec())
+    }
+  }
+  let input = adoc! {r#"
+    :data-uri:
+
+    image:ok.gif[] image:/tmp/secret.gif[] image:../../etc/x.gif[] image:secret.txt[]
+
+    |===
+    a|image:cell.gif[]
+    |===
+  "#};
+  for safe_mode in [
+    asciidork_meta::SafeMode::Safe,
+    asciidork_meta::SafeMode::Server,
+  ] {
+    let bump = &Bump::new();
+    let mut settings = JobSettings::embedded();
+    settings.safe_mode = safe_mode;
+    let mut parser = Parser::from_str(input, SourceFile::Tmp, bump);
+    parser.apply_job_settings(settings);
+    let document = parser.parse().unwrap().document;
+    let backend =
+      asciidork_dr_html_backend::AsciidoctorHtml::new().with_asset_loader(Box::new(Loader));
+    let html = asciidork_eval::eval(&document, backend).unwrap();
+    assert!(html.contains(r#"<img src="data:image/gif;base64,R0lG" alt="ok">"#));
+    assert!(html.contains(r#"<img src="/tmp/secret.gif" alt="secret">"#));
+    assert!(html.contains(r#"<img src="../../etc/x.gif" alt="x">"#));
+    assert!(html.contains(r#"<img src="secret.txt" alt="secret">"#));
+    assert!(html.contains(r#"<img src="data:image/gif;base64,R0lG" alt="cell">"#));
+  }
+}
+
 assert_html!(
   more_asciidoctor_image_tests,
   adoc! {r#"
@@ -420,3 +604,113 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  audio_block_macro,
+  adoc! {r#"
+    audio::clip.ogg[options="autoplay,loop"]
+
+    .Podcast
+    [.episode]
+    audio::ep1.mp3[start=60,end=90,opts=nocontrols]
+  "#},
+  html! {r#"
+    <div class="audioblock">
+      <div class="content">
+        <audio src="clip.ogg" autoplay controls loop>Your browser does not support the audio tag.</audio>
+      </div>
+    </div>
+    <div class="audioblock episode">
+      <div class="title">Podcast</div>
+      <div class="content">
+        <audio src="ep1.mp3#t=60,90">Your browser does not support the audio tag.</audio>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  video_block_macro,
+  adoc! {r#"
+    :imagesdir: media
+
+    .A movie
+    video::movie.mp4[width=640,options="autoplay,loop",start=10,end=20]
+
+    video::clip.webm[poster=poster.png,width=320,height=180,opts=nocontrols]
+  "#},
+  html! {r#"
+    <div class="videoblock">
+      <div class="title">A movie</div>
+      <div class="content">
+        <video src="media/movie.mp4#t=10,20" width="640" autoplay controls loop>Your browser does not support the video tag.</video>
+      </div>
+    </div>
+    <div class="videoblock">
+      <div class="content">
+        <video src="media/clip.webm" width="320" height="180" poster="media/poster.png">Your browser does not support the video tag.</video>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  video_block_macro_values_escaped,
+  adoc! {r#"
+    video::abc"onmouseover="x[youtube,theme="a?b",lang="x y"]
+
+    video::123[vimeo,hash="h#i",start="1 2"]
+
+    video::a"b.mp4[start="1?2"]
+  "#},
+  html! {r##"
+    <div class="videoblock">
+      <div class="content">
+        <iframe src="https://www.youtube.com/embed/abc%22onmouseover%3D%22x?rel=0&amp;theme=a%3Fb&amp;hl=x%20y" frameborder="0" allowfullscreen></iframe>
+      </div>
+    </div>
+    <div class="videoblock">
+      <div class="content">
+        <iframe src="https://player.vimeo.com/video/123?h=h%23i#at=1%202" frameborder="0" allowfullscreen></iframe>
+      </div>
+    </div>
+    <div class="videoblock">
+      <div class="content">
+        <video src="a%22b.mp4#t=1%3F2" controls>Your browser does not support the video tag.</video>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  video_block_macro_youtube_and_vimeo,
+  adoc! {r#"
+    video::rPQoq7ThGAU[youtube,640,360,start=30,opts="autoplay,loop"]
+
+    video::67480300[vimeo,opts=nofullscreen]
+  "#},
+  html! {r#"
+    <div class="videoblock">
+      <div class="content">
+        <iframe width="640" height="360" src="https://www.youtube.com/embed/rPQoq7ThGAU?rel=0&amp;start=30&amp;autoplay=1&amp;loop=1&amp;playlist=rPQoq7ThGAU" frameborder="0" allowfullscreen></iframe>
+      </div>
+    </div>
+    <div class="videoblock">
+      <div class="content">
+        <iframe src="https://player.vimeo.com/video/67480300" frameborder="0"></iframe>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  video_text_not_a_macro,
+  adoc! {r#"
+    Watch the video: video:foo.mp4[] is not inline.
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>Watch the video: video:foo.mp4[] is not inline.</p>
+    </div>
+  "#}
+);
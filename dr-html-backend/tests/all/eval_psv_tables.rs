@@ -36,6 +36,30 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  single_row_explicit_header,
+  adoc! {r#"
+    [%header]
+    |===
+    |a |b
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 50%;">
+        <col style="width: 50%;">
+      </colgroup>
+      <thead>
+        <tr>
+          <th class="tableblock halign-left valign-top">a</th>
+          <th class="tableblock halign-left valign-top">b</th>
+        </tr>
+      </thead>
+    </table>
+  "#}
+);
+
 assert_html!(
   table_cell_doctype,
   adoc! {r#"
@@ -160,6 +184,25 @@ assert_html!(
     r#"<table class="tableblock frame-ends grid-all stretch stripes-odd custom">"#
 );
 
+assert_html!(
+  table_grid_doc_default_w_partial_block_override,
+  adoc! {r#"
+    :table-grid: none
+
+    |===
+    |a
+    |===
+
+    [frame=none]
+    |===
+    |b
+    |===
+  "#},
+  contains:
+    r#"<table class="tableblock frame-all grid-none stretch">"#,
+    r#"<table class="tableblock frame-none grid-none stretch">"#,
+);
+
 assert_html!(
   cell_content_paragraphs,
   adoc! {r#"
@@ -330,6 +373,194 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  inferred_cols_w_spans,
+  adoc! {r#"
+    |===
+    2+|spans two |x
+    .2+|spans two rows |y |z
+    |m |n
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top" colspan="2">
+            <p class="tableblock">spans two</p>
+          </td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">x</p></td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-left valign-top" rowspan="2">
+            <p class="tableblock">spans two rows</p>
+          </td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">y</p></td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">z</p></td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">m</p></td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">n</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  cell_alignment_operators,
+  adoc! {r#"
+    [cols="^,>.>"]
+    |===
+    <|left .<|top
+    ^|center .^|middle
+    >|right .>|bottom
+    |g |h
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 50%;">
+        <col style="width: 50%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">left</p></td>
+          <td class="tableblock halign-right valign-top"><p class="tableblock">top</p></td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-center valign-top"><p class="tableblock">center</p></td>
+          <td class="tableblock halign-right valign-middle"><p class="tableblock">middle</p></td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-right valign-top"><p class="tableblock">right</p></td>
+          <td class="tableblock halign-right valign-bottom"><p class="tableblock">bottom</p></td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-center valign-top"><p class="tableblock">g</p></td>
+          <td class="tableblock halign-right valign-bottom"><p class="tableblock">h</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  col_content_styles_w_cell_override,
+  adoc! {r#"
+    [cols="1a,1e"]
+    |===
+    |* item |em
+    m|_mono_ |em
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 50%;">
+        <col style="width: 50%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top">
+            <div class="content">
+              <div class="ulist"><ul><li><p>item</p></li></ul></div>
+            </div>
+          </td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock"><em>em</em></p></td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock"><code><em>mono</em></code></p>
+          </td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock"><em>em</em></p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  col_repeat_specifiers,
+  adoc! {r#"
+    [cols="3*"]
+    |===
+    |a |b |c
+    |===
+
+    [cols="2*^,>"]
+    |===
+    |a |b |c
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">b</p></td>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">c</p></td>
+        </tr>
+      </tbody>
+    </table>
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+        <col style="width: 33.3333%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-center valign-top"><p class="tableblock">a</p></td>
+          <td class="tableblock halign-center valign-top"><p class="tableblock">b</p></td>
+          <td class="tableblock halign-right valign-top"><p class="tableblock">c</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  table_frame_grid_stripes_float_classes,
+  adoc! {r#"
+    [frame=ends,grid=rows,stripes=even,float=right]
+    |===
+    |a
+    |===
+
+    [frame=none,grid=cols,stripes=hover]
+    |===
+    |a
+    |===
+
+    [frame=sides,grid=none,stripes=all,float=left]
+    |===
+    |a
+    |===
+
+    [frame=topbot,stripes=odd]
+    |===
+    |a
+    |===
+  "#},
+  contains:
+    r#"<table class="tableblock frame-ends grid-rows stretch right stripes-even">"#,
+    r#"<table class="tableblock frame-none grid-cols stretch stripes-hover">"#,
+    r#"<table class="tableblock frame-sides grid-none stretch left stripes-all">"#,
+    r#"<table class="tableblock frame-ends grid-all stretch stripes-odd">"#,
+);
+
 assert_html!(
   table_float_class,
   adoc! {r#"
@@ -465,6 +696,63 @@ assert_html!(
   contains: "<colgroup><col><col></colgroup>" // <-- no width attrs
 );
 
+assert_html!(
+  autowidth_w_explicit_width,
+  adoc! {r#"
+    [%autowidth,width=50%]
+    |===
+    |a | b
+    |===
+  "#},
+  contains:
+    r#"<table class="tableblock frame-all grid-all" style="width: 50%;">"#,
+    "<colgroup><col><col></colgroup>",
+);
+
+assert_html!(
+  autowidth_w_full_width,
+  adoc! {r#"
+    [%autowidth,width=100%]
+    |===
+    |a | b
+    |===
+  "#},
+  contains:
+    r#"<table class="tableblock frame-all grid-all stretch">"#,
+    "<colgroup><col><col></colgroup>",
+);
+
+assert_html!(
+  unbreakable_table,
+  adoc! {r#"
+    [%unbreakable,width=50%]
+    |===
+    |a
+    |===
+
+    [%breakable]
+    |===
+    |b
+    |===
+  "#},
+  contains:
+    r#"<table class="tableblock frame-all grid-all" style="width: 50%; page-break-inside: avoid;">"#,
+    r#"<table class="tableblock frame-all grid-all stretch" style="page-break-inside: auto;">"#,
+);
+
+assert_html!(
+  autowidth_col_mixed_w_fixed,
+  adoc! {r#"
+    [cols="1,~,2"]
+    |===
+    |a |b |c
+    |===
+  "#},
+  contains:
+    r#"<table class="tableblock frame-all grid-all stretch">"#,
+    r#"<colgroup><col style="width: 1%;"><col><col style="width: 2%;"></colgroup>"#,
+);
+
 assert_html!(
   multibyte_separator,
   adoc! {r#"
@@ -490,3 +778,167 @@ assert_html!(
     r#"<p class="tableblock">one</p>"#,
     r#"<p class="tableblock">two</p>"#,
 );
+
+assert_html!(
+  noheader_option_suppresses_implicit_header,
+  adoc! {r#"
+    [%noheader]
+    |===
+    |one |two
+
+    |1 |2
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup>
+        <col style="width: 50%;">
+        <col style="width: 50%;">
+      </colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">one</p>
+          </td>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">two</p>
+          </td>
+        </tr>
+        <tr>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">1</p>
+          </td>
+          <td class="tableblock halign-left valign-top">
+            <p class="tableblock">2</p>
+          </td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  col_alignment_applies_to_header_and_footer,
+  adoc! {r#"
+    [cols="^",%footer]
+    |===
+    |head
+
+    |body
+    |foot
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all stretch">
+      <colgroup><col style="width: 100%;"></colgroup>
+      <thead>
+        <tr>
+          <th class="tableblock halign-center valign-top">head</th>
+        </tr>
+      </thead>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-center valign-top"><p class="tableblock">body</p></td>
+        </tr>
+      </tbody>
+      <tfoot>
+        <tr>
+          <td class="tableblock halign-center valign-top"><p class="tableblock">foot</p></td>
+        </tr>
+      </tfoot>
+    </table>
+  "#}
+);
+
+assert_html!(
+  table_id_and_roles,
+  adoc! {r#"
+    [.custom.wide#tbl1,frame=ends]
+    |===
+    |a
+    |===
+  "#},
+  html! {r#"
+    <table id="tbl1" class="tableblock frame-ends grid-all stretch custom wide">
+      <colgroup><col style="width: 100%;"></colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  percentage_and_proportional_col_widths,
+  adoc! {r#"
+    [cols="25%,75%"]
+    |===
+    |a |b
+    |===
+
+    [cols="20%,3"]
+    |===
+    |a |b
+    |===
+
+    [cols="90%,90%,90%,90%"]
+    |===
+    |a |b |c |d
+    |===
+  "#},
+  contains:
+    r#"<colgroup><col style="width: 25%;"><col style="width: 75%;"></colgroup>"#,
+    r#"<colgroup><col style="width: 86.9565%;"><col style="width: 13.0435%;"></colgroup>"#,
+    r#"<colgroup><col style="width: 25%;"><col style="width: 25%;"><col style="width: 25%;"><col style="width: 25%;"></colgroup>"#,
+);
+
+assert_html!(
+  single_col_autowidth,
+  adoc! {r#"
+    [%autowidth]
+    |===
+    |a
+    |===
+  "#},
+  html! {r#"
+    <table class="tableblock frame-all grid-all fit-content">
+      <colgroup><col></colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "#}
+);
+
+assert_html!(
+  hardbreaks_in_cells,
+  adoc! {r#"
+    :hardbreaks-option:
+
+    |===
+    |a
+    b
+    a|c
+    d
+    |===
+
+    :!hardbreaks-option:
+
+    |===
+    a|[%hardbreaks]
+    e
+    f
+
+    g
+    h
+    |===
+  "#},
+  contains:
+    r#"<p class="tableblock">a<br> b</p>"#,
+    r#"<div class="paragraph"><p>c<br> d</p></div>"#,
+    r#"<div class="paragraph"><p>e<br> f</p></div><div class="paragraph"><p>g h</p></div>"#,
+);
@@ -64,6 +64,37 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  duplicate_section_ids_w_toc,
+  adoc! {r#"
+    = Doc
+    :toc:
+    :idprefix: id_
+    :idseparator: -
+
+    == Overview
+
+    == Overview
+  "#},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li><a href="#id_overview">Overview</a></li>
+        <li><a href="#id_overview-2">Overview</a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="id_overview">Overview</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="id_overview-2">Overview</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "##}
+);
+
 assert_html!(
   single_2_simple_sections,
   adoc! {r#"
@@ -258,6 +289,55 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  empty_idprefix,
+  adoc! {r#"
+    :idprefix:
+
+    == Getting Started
+
+    See <<getting_started>>.
+  "#},
+  html! {r##"
+    <div class="sect1">
+      <h2 id="getting_started">Getting Started</h2>
+      <div class="sectionbody">
+        <div class="paragraph"><p>See <a href="#getting_started">Getting Started</a>.</p></div>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  dash_idseparator_collapses_w_toc,
+  adoc! {r#"
+    = Doc
+    :toc:
+    :idseparator: -
+
+    == Getting   Started -- Now!
+
+    == A & B - C
+  "#},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li><a href="#_getting-started-now">Getting Started -- Now!</a></li>
+        <li><a href="#_a-b-c">A &amp; B - C</a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_getting-started-now">Getting Started -- Now!</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_a-b-c">A &amp; B - C</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "##}
+);
+
 assert_html!(
   explicit_id_sequenced,
   adoc! {r#"
@@ -567,6 +647,49 @@ assert_html!(
   "#}
 );
 
+assert_html!(
+  appendix_sections,
+  adoc! {r#"
+    :sectnums:
+
+    == sect 1
+
+    [appendix]
+    == First
+
+    [appendix%unnumbered]
+    == Second
+
+    [.appendix%unnumbered]
+    == Third
+
+    [appendix]
+    == Fourth
+  "#},
+  html! {r#"
+    <div class="sect1">
+      <h2 id="_sect_1">1. sect 1</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_first">Appendix A: First</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_second">Second</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1 appendix">
+      <h2 id="_third">Third</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_fourth">Appendix B: Fourth</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "#}
+);
+
 assert_html!(
   custom_attrs,
   adoc! {r#"
@@ -617,3 +740,44 @@ assert_html!(
     </div>
   "#}
 );
+
+assert_html!(
+  embedded_nested_sections_wo_chrome,
+  adoc! {r#"
+    = Document Title
+
+    Preamble
+
+    == Section 1
+
+    === Section 1.1
+
+    Nested content.
+
+    == Section 2
+  "#},
+  html! {r#"
+    <div id="preamble">
+      <div class="sectionbody">
+        <div class="paragraph">
+          <p>Preamble</p>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">Section 1</h2>
+      <div class="sectionbody">
+        <div class="sect2">
+          <h3 id="_section_1_1">Section 1.1</h3>
+          <div class="paragraph">
+            <p>Nested content.</p>
+          </div>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_2">Section 2</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "#}
+);
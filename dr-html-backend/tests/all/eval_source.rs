@@ -169,6 +169,215 @@ assert_html!(
   "#})
 );
 
+assert_html!(
+  listing_vs_paragraph_default_subs,
+  adoc! {r#"
+    ----
+    *not bold* <tag> <1>
+    ----
+
+    *bold* <tag>
+
+    [source,ruby]
+    ----
+    puts *x* <1>
+    ----
+    <1> callout
+  "#},
+  html! {r#"
+    <div class="listingblock">
+      <div class="content">
+        <pre>*not bold* &lt;tag&gt; <b class="conum">(1)</b></pre>
+      </div>
+    </div>
+    <div class="paragraph"><p><strong>bold</strong> &lt;tag&gt;</p></div>
+    <div class="listingblock">
+      <div class="content">
+        <pre class="highlight"><code class="language-ruby" data-lang="ruby" translate="no">puts *x* <b class="conum">(1)</b></code></pre>
+      </div>
+    </div>
+    <div class="colist arabic">
+      <ol>
+        <li><p>callout</p></li>
+      </ol>
+    </div>
+  "#}
+);
+
+assert_html!(
+  multichar_whitespace_preserved_in_verbatim,
+  adoc! {r#"
+    [subs=normal]
+    ....
+    a    *b*
+    ....
+
+    x    y
+
+    [verse]
+    ____
+    v    w
+    ____
+  "#},
+  html! {r#"
+    <div class="literalblock">
+      <div class="content"><pre>a    <strong>b</strong></pre></div>
+    </div>
+    <div class="paragraph"><p>x y</p></div>
+    <div class="verseblock"><pre class="content">v    w</pre></div>
+  "#}
+);
+
+assert_html!(
+  verbatim_block_roles,
+  adoc! {r#"
+    [.wrap]
+    ----
+    x
+    ----
+
+    [.nowrap#lit]
+    ....
+    y
+    ....
+
+    [source.wrap,ruby]
+    ----
+    z
+    ----
+  "#},
+  html! {r#"
+    <div class="listingblock wrap">
+      <div class="content"><pre>x</pre></div>
+    </div>
+    <div id="lit" class="literalblock nowrap">
+      <div class="content"><pre>y</pre></div>
+    </div>
+    <div class="listingblock wrap">
+      <div class="content">
+        <pre class="highlight"><code class="language-ruby" data-lang="ruby" translate="no">z</code></pre>
+      </div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  rouge_source_highlighter,
+  adoc! {r#"
+    :source-highlighter: rouge
+
+    [source,ruby]
+    ----
+    def hi # <1>
+      puts "Hi #{1}"
+    end
+    ----
+  "#},
+  wrap_source(
+    "ruby",
+    raw_html! {r#"
+      <span class="tok-kw">def</span> hi <span class="tok-cmt"># </span><b class="conum">(1)</b>
+        puts <span class="tok-str">"Hi #{1}"</span>
+      <span class="tok-kw">end</span>
+    "#}
+  )
+);
+
+assert_html!(
+  highlightjs_source_highlighter,
+  adoc! {r#"
+    :source-highlighter: highlight.js
+
+    [source,ruby]
+    ----
+    def hi
+    ----
+  "#},
+  wrap_source("ruby", "def hi")
+);
+
+assert_html!(
+  source_linenums,
+  adoc! {r#"
+    [source,ruby,linenums]
+    ----
+    puts 1
+    puts 2
+    ----
+  "#},
+  wrap_source(
+    "ruby",
+    raw_html! {r#"
+      <table class="linenotable"><tbody><tr><td class="linenos"><pre class="lineno">1
+      2</pre></td><td class="code"><pre>puts 1
+      puts 2</pre></td></tr></tbody></table>
+    "#}
+  )
+);
+
+assert_html!(
+  source_highlight_lines,
+  adoc! {r#"
+    [source,ruby,highlight="1,3..4"]
+    ----
+    a
+    b
+    c
+    d
+    e
+    ----
+  "#},
+  wrap_source(
+    "ruby",
+    raw_html! {r#"
+      <span class="highlighted">a</span>
+      b
+      <span class="highlighted">c</span>
+      <span class="highlighted">d</span>
+      e
+    "#}
+  )
+);
+
+assert_html!(
+  source_highlight_huge_and_open_ranges,
+  adoc! {r#"
+    [source,ruby,highlight="2..100000000000000,1.."]
+    ----
+    a
+    b
+    c
+    ----
+  "#},
+  wrap_source(
+    "ruby",
+    raw_html! {r#"
+      <span class="highlighted">a</span>
+      <span class="highlighted">b</span>
+      <span class="highlighted">c</span>
+    "#}
+  )
+);
+
+assert_html!(
+  source_linenums_option_w_highlight,
+  adoc! {r#"
+    [source%linenums,js,highlight=2]
+    ----
+    x
+    y
+    ----
+  "#},
+  wrap_source(
+    "js",
+    raw_html! {r#"
+      <table class="linenotable"><tbody><tr><td class="linenos"><pre class="lineno">1
+      2</pre></td><td class="code"><pre>x
+      <span class="highlighted">y</span></pre></td></tr></tbody></table>
+    "#}
+  )
+);
+
 // helpers
 
 fn wrap_listing(inner: &str) -> String {
@@ -187,7 +396,7 @@ fn wrap_literal(inner: &str) -> String {
 
 fn wrap_source(lang: &str, inner: &str) -> String {
   wrap_listing(&format!(
-    r#"<pre class="highlight"><code class="language-{lang}" data-lang="{lang}">{}</code></pre>"#,
+    r#"<pre class="highlight"><code class="language-{lang}" data-lang="{lang}" translate="no">{}</code></pre>"#,
     inner.trim(),
   ))
 }
@@ -22,6 +22,200 @@ assert_standalone_body!(
   "#}
 );
 
+assert_standalone_body!(
+  author_middle_name_and_initials,
+  adoc! {r#"
+    = Document Title
+    :author: John Quincy Public
+
+    {author}, {firstname}, {middlename}, {lastname}, {authorinitials}
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="author" class="author">John Quincy Public</span><br>
+        </div>
+      </div>
+      <div id="content">
+        <div class="paragraph">
+          <p>John Quincy Public, John, Quincy, Public, JQP</p>
+        </div>
+      </div>
+      <div id="footer"></div>
+    </body>
+  "#}
+);
+
+assert_standalone_body!(
+  toc_macro_not_in_header,
+  adoc! {r#"
+    = Doc
+    :toc: macro
+
+    intro
+
+    toc::[]
+
+    == A
+  "#},
+  html! {r##"
+    <body class="article">
+      <div id="header"><h1>Doc</h1></div>
+      <div id="content">
+        <div id="preamble">
+          <div class="sectionbody">
+            <div class="paragraph"><p>intro</p></div>
+            <div id="toc" class="toc">
+              <div id="toctitle">Table of Contents</div>
+              <ul class="sectlevel1"><li><a href="#_a">A</a></li></ul>
+            </div>
+          </div>
+        </div>
+        <div class="sect1">
+          <h2 id="_a">A</h2>
+          <div class="sectionbody"></div>
+        </div>
+      </div>
+      <div id="footer"></div>
+    </body>
+  "##}
+);
+
+assert_standalone_body!(
+  multiple_authors,
+  adoc! {r#"
+    = Document Title
+    Bob Foo <bob@foo.com>; Jane Q. Baz <jane@baz.com>
+
+    {author_2} {email_2} {authorinitials_2} ({authors})
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="author" class="author">Bob Foo</span><br>
+          <span id="email" class="email"><a href="mailto:bob@foo.com">bob@foo.com</a></span><br>
+          <span id="author2" class="author">Jane Q. Baz</span><br>
+          <span id="email2" class="email"><a href="mailto:jane@baz.com">jane@baz.com</a></span><br>
+        </div>
+      </div>
+      <div id="content">
+        <div class="paragraph">
+          <p>Jane Q. Baz <a href="mailto:jane@baz.com">jane@baz.com</a> JQB (Bob Foo, Jane Q. Baz)</p>
+        </div>
+      </div>
+      <div id="footer"></div>
+    </body>
+  "#}
+);
+
+assert_standalone_body!(
+  email_attr_overrides_author_line,
+  adoc! {r#"
+    = Document Title
+    Bob Foo <bob@foo.com>
+    :email: bob@example.com
+
+    {email}
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="author" class="author">Bob Foo</span><br>
+          <span id="email" class="email"><a href="mailto:bob@example.com">bob@example.com</a></span><br>
+        </div>
+      </div>
+      <div id="content">
+        <div class="paragraph">
+          <p><a href="mailto:bob@example.com">bob@example.com</a></p>
+        </div>
+      </div>
+      <div id="footer"></div>
+    </body>
+  "#}
+);
+
+assert_standalone_body!(
+  revision_details,
+  adoc! {r#"
+    = Document Title
+    Bob Smith
+    v1.2, 2024-01-01: Draft
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="author" class="author">Bob Smith</span><br>
+          <span id="revnumber">version 1.2,</span><br>
+          <span id="revdate">2024-01-01</span><br>
+          <br><span id="revremark">Draft</span><br>
+        </div>
+      </div>
+      <div id="content"></div>
+      <div id="footer">
+        <div id="footer-text">Version 1.2<br></div>
+      </div>
+    </body>
+  "#}
+);
+
+assert_standalone_body!(
+  custom_version_label,
+  adoc! {r#"
+    = Document Title
+    :revnumber: 3.0
+    :version-label: Release
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="revnumber">release 3.0</span><br>
+        </div>
+      </div>
+      <div id="content"></div>
+      <div id="footer">
+        <div id="footer-text">Release 3.0<br></div>
+      </div>
+    </body>
+  "#}
+);
+
+assert_standalone_body!(
+  revision_details_escaped_wo_version_label,
+  adoc! {r#"
+    = Document Title
+    :revnumber: <1.0>
+    :revdate: 2024 & beyond
+    :revremark: <b>Draft</b>
+    :version-label!:
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header">
+        <h1>Document Title</h1>
+        <div class="details">
+          <span id="revnumber">&lt;1.0&gt;,</span><br>
+          <span id="revdate">2024 &amp; beyond</span><br>
+          <br><span id="revremark">&lt;b&gt;Draft&lt;/b&gt;</span><br>
+        </div>
+      </div>
+      <div id="content"></div>
+      <div id="footer">
+        <div id="footer-text">&lt;1.0&gt;<br></div>
+      </div>
+    </body>
+  "#}
+);
+
 assert_standalone_body!(
   disable_doc_sections,
   adoc! {r#"
@@ -36,6 +230,25 @@ assert_standalone_body!(
   "#}
 );
 
+assert_standalone_body!(
+  doc_wrapper_classes,
+  adoc! {r#"
+    = Document Title
+    :header-class: masthead
+    :content-class: wide dark
+    :footer-class: foot
+  "#},
+  html! {r#"
+    <body class="article">
+      <div id="header" class="masthead">
+        <h1>Document Title</h1>
+      </div>
+      <div id="content" class="wide dark"></div>
+      <div id="footer" class="foot"></div>
+    </body>
+  "#}
+);
+
 assert_standalone_body!(
   doc_attrs_after_comment,
   adoc! {r#"
@@ -0,0 +1,272 @@
+use test_utils::*;
+
+assert_html!(
+  stem_blocks,
+  adoc! {r#"
+    [stem]
+    ++++
+    sqrt(4) = 2 < 3
+    ++++
+
+    .Title
+    [latexmath]
+    ++++
+    \sqrt{4} = 2
+    ++++
+  "#},
+  html! {r#"
+    <div class="stemblock">
+      <div class="content">\$sqrt(4) = 2 &lt; 3\$</div>
+    </div>
+    <div class="stemblock">
+      <div class="title">Title</div>
+      <div class="content">\[\sqrt{4} = 2\]</div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  stem_paragraph_matches_delimited_block,
+  adoc! {r#"
+    [stem]
+    sqrt(4) = 2 < 3
+
+    [stem]
+    ++++
+    sqrt(4) = 2 < 3
+    ++++
+
+    [latexmath]
+    \sqrt{4} = 2
+
+    [latexmath]
+    ++++
+    \sqrt{4} = 2
+    ++++
+  "#},
+  html! {r#"
+    <div class="stemblock">
+      <div class="content">\$sqrt(4) = 2 &lt; 3\$</div>
+    </div>
+    <div class="stemblock">
+      <div class="content">\$sqrt(4) = 2 &lt; 3\$</div>
+    </div>
+    <div class="stemblock">
+      <div class="content">\[\sqrt{4} = 2\]</div>
+    </div>
+    <div class="stemblock">
+      <div class="content">\[\sqrt{4} = 2\]</div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  stem_block_style_overrides_doc_default,
+  adoc! {r#"
+    :stem: asciimath
+
+    [latexmath]
+    ++++
+    \alpha
+    ++++
+
+    [stem]
+    ++++
+    alpha
+    ++++
+  "#},
+  html! {r#"
+    <div class="stemblock">
+      <div class="content">\[\alpha\]</div>
+    </div>
+    <div class="stemblock">
+      <div class="content">\$alpha\$</div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  stem_style_resolves_doc_default,
+  adoc! {r#"
+    :stem: latexmath
+
+    [stem]
+    ++++
+    \alpha
+    ++++
+
+    [asciimath]
+    alpha
+  "#},
+  html! {r#"
+    <div class="stemblock">
+      <div class="content">\[\alpha\]</div>
+    </div>
+    <div class="stemblock">
+      <div class="content">\$alpha\$</div>
+    </div>
+  "#}
+);
+
+assert_html!(
+  inline_stem_macros,
+  adoc! {r#"
+    :stem: latexmath
+
+    stem:[\sqrt{4} < 3], asciimath:[x_1 [a\]] and latexmath:[\alpha]
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>\(\sqrt{4} &lt; 3\), \$x_1 [a]\$ and \(\alpha\)</p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  inline_stem_spans_lines,
+  adoc! {r#"
+    :stem: latexmath
+
+    Solve stem:[a +
+    b] now, then asciimath:[x
+    y
+    z] done
+  "#},
+  html! {
+    r#"
+      <div class="paragraph"><p>{}</p></div>
+    "#,
+    r#"
+      Solve \(a +
+      b\) now, then \$x
+      y
+      z\$ done
+    "#
+  }
+);
+
+assert_html!(
+  stem_block_macro_syntax_is_a_paragraph,
+  adoc! {r#"
+    stem::[x]
+
+    kbd::[F1]
+  "#},
+  html! {r#"
+    <div class="paragraph"><p>stem::[x]</p></div>
+    <div class="paragraph"><p>kbd::[F1]</p></div>
+  "#}
+);
+
+assert_html!(
+  stem_in_section_title_and_toc,
+  adoc! {r#"
+    = Document Title
+    :toc:
+
+    == The stem:[sqrt(4) = 2] formula
+
+    .Block stem:[x^2] title
+    foo
+  "#},
+  contains:
+    r##"<li><a href="#_the_stemsqrt4_2_formula">The \$sqrt(4) = 2\$ formula</a></li>"##,
+    r#"<h2 id="_the_stemsqrt4_2_formula">The \$sqrt(4) = 2\$ formula</h2>"#,
+    r#"<div class="title">Block \$x^2\$ title</div>"#,
+);
+
+assert_html!(
+  stem_in_footnote,
+  adoc! {r#"
+    :stem:
+
+    foo footnote:[see stem:[x^2]] bar
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>foo <sup class="footnote">[<a id="_footnoteref_1" class="footnote" href="#_footnotedef_1" title="View footnote.">1</a>]</sup> bar</p>
+    </div>
+    <div id="footnotes">
+      <hr>
+      <div class="footnote" id="_footnotedef_1">
+        <a href="#_footnoteref_1">1</a>. see \$x^2\$
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  stem_fallback_wraps_math,
+  adoc! {r#"
+    :stem: latexmath
+    :stem-fallback:
+
+    [stem]
+    ++++
+    x < 2
+    ++++
+
+    Inline stem:[\alpha] and asciimath:[sqrt(4)].
+  "#},
+  html! {r#"
+    <div class="stemblock">
+      <div class="content"><span class="math">x &lt; 2</span></div>
+    </div>
+    <div class="paragraph">
+      <p>Inline <span class="math">\alpha</span> and <span class="math">sqrt(4)</span>.</p>
+    </div>
+  "#}
+);
+
+assert_html!(
+  inline_stem_in_list_items_and_admonitions,
+  adoc! {r#"
+    :stem:
+
+    * item stem:[x^2]
+
+    NOTE: note stem:[y]
+
+    [TIP]
+    ====
+    compound stem:[z]
+    ====
+  "#},
+  html! {r#"
+    <div class="ulist">
+      <ul>
+        <li><p>item \$x^2\$</p></li>
+      </ul>
+    </div>
+    <div class="admonitionblock note">
+      <table>
+        <tr>
+          <td class="icon"><div class="title">Note</div></td>
+          <td class="content">note \$y\$</td>
+        </tr>
+      </table>
+    </div>
+    <div class="admonitionblock tip">
+      <table>
+        <tr>
+          <td class="icon"><div class="title">Tip</div></td>
+          <td class="content">
+            <div class="paragraph"><p>compound \$z\$</p></div>
+          </td>
+        </tr>
+      </table>
+    </div>
+  "#}
+);
+
+assert_html!(
+  unterminated_inline_stem_as_text,
+  adoc! {r#"
+    Solve stem:[x + 1 = 2 now
+  "#},
+  html! {r#"
+    <div class="paragraph">
+      <p>Solve stem:[x + 1 = 2 now</p>
+    </div>
+  "#}
+);
@@ -107,6 +107,30 @@ assert_html!(
   "##}
 );
 
+assert_html!(
+  toc_macro_ignored_unless_macro_position,
+  adoc! {"
+    = Doc Title
+    :toc:
+
+    == Section 1
+
+    toc::[]
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li><a href="#_section_1">Section 1</a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">Section 1</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "##}
+);
+
 assert_html!(
   nested_toc,
   adoc! {"
@@ -189,3 +213,193 @@ test_non_embedded_contains!(
     r#"<div id="toc" class="toc2">"#
   ],
 );
+
+assert_html!(
+  toc_w_sectnums,
+  adoc! {"
+    = Doc Title
+    :toc:
+    :sectnums:
+
+    == Section 1
+
+    === Sub
+
+    == Section 2
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li>
+          <a href="#_section_1">1. Section 1</a>
+          <ul class="sectlevel2">
+            <li><a href="#_sub">1.1. Sub</a></li>
+          </ul>
+        </li>
+        <li><a href="#_section_2">2. Section 2</a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">1. Section 1</h2>
+      <div class="sectionbody">
+        <div class="sect2"><h3 id="_sub">1.1. Sub</h3></div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_2">2. Section 2</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  toc_generated_ids_match_headings_and_xrefs,
+  adoc! {"
+    :toc:
+    :idprefix: sec-
+
+    == Sect One
+
+    See <<sec-sect_one>>.
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li><a href="#sec-sect_one">Sect One</a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="sec-sect_one">Sect One</h2>
+      <div class="sectionbody">
+        <div class="paragraph"><p>See <a href="#sec-sect_one">Sect One</a>.</p></div>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  toc_appendix_entries_lettered,
+  adoc! {"
+    = Doc Title
+    :toc:
+    :sectnums:
+
+    == Section 1
+
+    [appendix]
+    == First
+
+    [appendix%unnumbered]
+    == Second
+
+    [appendix]
+    == Third
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li><a href="#_section_1">1. Section 1</a></li>
+        <li><a href="#_first">Appendix A: First</a></li>
+        <li><a href="#_second">Second</a></li>
+        <li><a href="#_third">Appendix B: Third</a></li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">1. Section 1</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_first">Appendix A: First</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_second">Second</h2>
+      <div class="sectionbody"></div>
+    </div>
+    <div class="sect1">
+      <h2 id="_third">Appendix B: Third</h2>
+      <div class="sectionbody"></div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  toc_appendix_subsections_numbered_after_letter,
+  adoc! {"
+    = Doc Title
+    :toc:
+    :sectnums:
+
+    == Section 1
+
+    === Sub
+
+    [appendix]
+    == D
+
+    === D1
+
+    === D2
+
+    [appendix]
+    == E
+
+    === E1
+  "},
+  html! {r##"
+    <div id="toc" class="toc">
+      <div id="toctitle">Table of Contents</div>
+      <ul class="sectlevel1">
+        <li>
+          <a href="#_section_1">1. Section 1</a>
+          <ul class="sectlevel2">
+            <li><a href="#_sub">1.1. Sub</a></li>
+          </ul>
+        </li>
+        <li>
+          <a href="#_d">Appendix A: D</a>
+          <ul class="sectlevel2">
+            <li><a href="#_d1">A.1. D1</a></li>
+            <li><a href="#_d2">A.2. D2</a></li>
+          </ul>
+        </li>
+        <li>
+          <a href="#_e">Appendix B: E</a>
+          <ul class="sectlevel2">
+            <li><a href="#_e1">B.1. E1</a></li>
+          </ul>
+        </li>
+      </ul>
+    </div>
+    <div class="sect1">
+      <h2 id="_section_1">1. Section 1</h2>
+      <div class="sectionbody">
+        <div class="sect2">
+          <h3 id="_sub">1.1. Sub</h3>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_d">Appendix A: D</h2>
+      <div class="sectionbody">
+        <div class="sect2">
+          <h3 id="_d1">A.1. D1</h3>
+        </div>
+        <div class="sect2">
+          <h3 id="_d2">A.2. D2</h3>
+        </div>
+      </div>
+    </div>
+    <div class="sect1">
+      <h2 id="_e">Appendix B: E</h2>
+      <div class="sectionbody">
+        <div class="sect2">
+          <h3 id="_e1">B.1. E1</h3>
+        </div>
+      </div>
+    </div>
+  "##}
+);
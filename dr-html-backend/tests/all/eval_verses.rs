@@ -100,3 +100,29 @@ assert_html!(
     "#
   }
 );
+
+// asciidoctor has no backslash line continuation, in verse
+// a trailing `\` is literal and the line break is preserved
+assert_html!(
+  verse_trailing_backslash,
+  adoc! {r#"
+    [verse]
+    ____
+    soft \
+    wrap\
+    here
+    ____
+  "#},
+  html! {
+    r#"
+      <div class="verseblock">
+        <pre class="content">{}</pre>
+      </div>
+    "#,
+    r#"
+      soft \
+      wrap\
+      here
+    "#
+  }
+);
@@ -56,6 +56,68 @@ assert_html!(
   "##}
 );
 
+assert_html!(
+  xref_section_block_anchor_reftext,
+  adoc! {r#"
+    == Overview
+
+    See <<_overview>> and <<other>>.
+
+    [[other,Elsewhere]]
+    == Other
+  "#},
+  contains: r##"See <a href="#_overview">Overview</a> and <a href="#other">Elsewhere</a>."##
+);
+
+assert_html!(
+  xref_macro_and_shorthand_forms,
+  adoc! {r#"
+    == Tigers
+
+    xref:_tigers[] and <<_tigers>>, xref:_tigers[big cats] and <<_tigers,big cats>>.
+  "#},
+  contains: r##"<p><a href="#_tigers">Tigers</a> and <a href="#_tigers">Tigers</a>, <a href="#_tigers">big cats</a> and <a href="#_tigers">big cats</a>.</p>"##
+);
+
+assert_html!(
+  interdocument_xrefs,
+  adoc! {r#"
+    xref:other.adoc#id[Other] and <<other.adoc#id,Other>>.
+
+    xref:other.adoc[] and <<other#>> and <<other.adoc#id>>.
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p><a href="other.html#id">Other</a> and <a href="other.html#id">Other</a>.</p>
+    </div>
+    <div class="paragraph">
+      <p><a href="other.html">other.html</a> and <a href="other.html">other.html</a> and <a href="other.html#id">other.html</a>.</p>
+    </div>
+  "##}
+);
+
+assert_html!(
+  interdocument_xrefs_escaped,
+  adoc! {r#"
+    <<x"onclick="y.adoc#s>> and xref:a<b>.adoc[]
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p><a href="x&quot;onclick=&quot;y.html#s">x"onclick="y.html</a> and <a href="a&lt;b&gt;.html">a&lt;b&gt;.html</a></p>
+    </div>
+  "##}
+);
+
+assert_html!(
+  interdocument_xref_outfilesuffix,
+  adoc! {r#"
+    :outfilesuffix: .htm
+
+    See <<guide/setup.adoc#install,install>>.
+  "#},
+  contains: r##"See <a href="guide/setup.htm#install">install</a>."##
+);
+
 assert_html!(
   xref_custom_reftext,
   adoc! {r#"
@@ -282,3 +344,480 @@ assert_html!(
     </table>
   "##}
 );
+
+assert_html!(
+  xrefstyle_numbered_blocks,
+  adoc! {r#"
+    :xrefstyle: full
+
+    See <<cat>>, <<tbl>> and <<cat,the cat>>.
+
+    image::plain.png[]
+
+    [#cat]
+    .A *cat*
+    image::cat.png[]
+
+    [#tbl]
+    .Data
+    |===
+    |a
+    |===
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>See <a href="#cat">Figure 1, &#8220;A <strong>cat</strong>&#8221;</a>, <a href="#tbl">Table 1, &#8220;Data&#8221;</a> and <a href="#cat">the cat</a>.</p>
+    </div>
+    <div class="imageblock">
+      <div class="content"><img src="plain.png" alt="plain"></div>
+    </div>
+    <div id="cat" class="imageblock">
+      <div class="content"><img src="cat.png" alt="cat"></div>
+      <div class="title">Figure 1. A <strong>cat</strong></div>
+    </div>
+    <table id="tbl" class="tableblock frame-all grid-all stretch">
+      <caption class="title">Table 1. Data</caption>
+      <colgroup><col style="width: 100%;"></colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+        </tr>
+      </tbody>
+    </table>
+  "##}
+);
+
+assert_html!(
+  xrefstyle_short_and_basic,
+  adoc! {r#"
+    :xrefstyle: short
+
+    See <<cat>> and <<dog>>.
+
+    [#cat]
+    .Cat
+    image::cat.png[]
+
+    [#dog,reftext=Rover]
+    .Dog
+    image::dog.png[]
+  "#},
+  contains: r##"See <a href="#cat">Figure 1</a> and <a href="#dog">Rover</a>."##
+);
+
+assert_html!(
+  xref_caption_prefix_escaped,
+  adoc! {r#"
+    :figure-caption: <b>Fig & co
+    :xrefstyle: short
+
+    See <<cat>>.
+
+    [#cat]
+    .Cat
+    image::cat.png[]
+  "#},
+  contains: r##"See <a href="#cat">&lt;b&gt;Fig &amp; co 1</a>."##
+);
+
+assert_html!(
+  xref_caption_numbers_match_rendered_captions,
+  adoc! {r#"
+    :figure-caption: Fig
+    :table-caption: Tbl
+    :xrefstyle: short
+
+    .A
+    image::a.png[]
+
+    [cols=1a]
+    |===
+    |
+    .Inner
+    image::b.png[]
+    |===
+
+    [#c]
+    .C
+    image::c.png[]
+
+    [#t]
+    .T
+    |===
+    |cell
+    |===
+
+    See <<c>> and <<t>>.
+  "#},
+  contains:
+    r#"<div class="title">Fig 1. A</div>"#,
+    r#"<div class="title">Fig 2. Inner</div>"#,
+    r#"<div class="title">Fig 3. C</div>"#,
+    r#"<caption class="title">Tbl 1. T</caption>"#,
+    r##"See <a href="#c">Fig 3</a> and <a href="#t">Tbl 1</a>."##,
+);
+
+assert_html!(
+  xrefstyle_basic,
+  adoc! {r#"
+    :xrefstyle: basic
+
+    See <<cat>>.
+
+    [#cat]
+    .Cat
+    image::cat.png[]
+  "#},
+  contains: r##"See <a href="#cat">Cat</a>."##
+);
+
+assert_html!(
+  xrefstyle_full_equation,
+  adoc! {r#"
+    :stem: latexmath
+    :eqnums: all
+    :xrefstyle: full
+
+    [stem]
+    ++++
+    a^2
+    ++++
+
+    [stem#pythag]
+    ++++
+    a^2 + b^2 = c^2
+    ++++
+
+    See <<pythag>>.
+  "#},
+  contains: r##"See <a href="#pythag">Equation (2)</a>."##
+);
+
+assert_html!(
+  xrefstyle_short_equation,
+  adoc! {r#"
+    :stem: latexmath
+    :eqnums:
+    :xrefstyle: short
+
+    [stem#pythag]
+    ++++
+    \begin{equation}
+    a^2 + b^2 = c^2
+    \end{equation}
+    ++++
+
+    See <<pythag>> and <<pythag,the theorem>>.
+  "#},
+  contains: r##"See <a href="#pythag">(1)</a> and <a href="#pythag">the theorem</a>."##
+);
+
+assert_html!(
+  xrefstyle_basic_equation,
+  adoc! {r#"
+    :stem: latexmath
+    :eqnums: all
+
+    [stem#e1]
+    ++++
+    e^{i\pi} = -1
+    ++++
+
+    .Euler
+    [stem#e2]
+    ++++
+    e^{i\pi} + 1 = 0
+    ++++
+
+    See <<e1>> and <<e2>>.
+  "#},
+  contains: r##"See <a href="#e1">(1)</a> and <a href="#e2">Euler</a>."##
+);
+
+assert_html!(
+  equations_unnumbered_wo_eqnums,
+  |s: &mut JobSettings| s.strict = false,
+  adoc! {r#"
+    :stem: latexmath
+    :xrefstyle: short
+
+    [stem#pythag]
+    ++++
+    a^2 + b^2 = c^2
+    ++++
+
+    See <<pythag>>.
+  "#},
+  contains: r##"See <a href="#pythag">[pythag]</a>."##
+);
+
+assert_html!(
+  ams_eqnums_number_only_equation_environments,
+  |s: &mut JobSettings| s.strict = false,
+  adoc! {r#"
+    :stem: latexmath
+    :eqnums:
+    :xrefstyle: short
+
+    [stem#plain]
+    ++++
+    a^2
+    ++++
+
+    [stem#env]
+    ++++
+    \begin{equation}
+    a^2 + b^2 = c^2
+    \end{equation}
+    ++++
+
+    [stem#starred]
+    ++++
+    \begin{equation*}
+    e^{i\pi} = -1
+    \end{equation*}
+    ++++
+
+    See <<plain>>, <<env>> and <<starred>>.
+  "#},
+  contains: r##"See <a href="#plain">[plain]</a>, <a href="#env">(1)</a> and <a href="#starred">[starred]</a>."##
+);
+
+assert_html!(
+  asciimath_equations_never_numbered,
+  |s: &mut JobSettings| s.strict = false,
+  adoc! {r#"
+    :stem:
+    :eqnums: all
+    :xrefstyle: short
+
+    [stem#am]
+    ++++
+    sqrt(4) = 2
+    ++++
+
+    [latexmath#lm]
+    ++++
+    \sqrt{4} = 2
+    ++++
+
+    See <<am>> and <<lm>>.
+  "#},
+  contains: r##"See <a href="#am">[am]</a> and <a href="#lm">(1)</a>."##
+);
+
+assert_html!(
+  equation_numbers_shared_with_table_cells,
+  adoc! {r#"
+    :stem: latexmath
+    :eqnums: all
+    :xrefstyle: short
+
+    [stem]
+    ++++
+    a^2
+    ++++
+
+    |===
+    a|
+    [stem]
+    ++++
+    b^2
+    ++++
+    |===
+
+    [stem#c]
+    ++++
+    c^2
+    ++++
+
+    See <<c>>.
+  "#},
+  contains: r##"See <a href="#c">(3)</a>."##
+);
+
+assert_html!(
+  id_and_role_shorthand_on_any_block,
+  adoc! {r#"
+    See <<p>>, <<l>>, <<t>> and <<i>>.
+
+    [#p.highlight]
+    para
+
+    [#l.highlight]
+    .Code
+    ----
+    code
+    ----
+
+    [#t.highlight]
+    .Data
+    |===
+    |a
+    |===
+
+    [#i.highlight]
+    .Cat
+    image::cat.png[]
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>See <a href="#p">[p]</a>, <a href="#l">Code</a>, <a href="#t">Data</a> and <a href="#i">Cat</a>.</p>
+    </div>
+    <div id="p" class="paragraph highlight"><p>para</p></div>
+    <div id="l" class="listingblock highlight">
+      <div class="title">Code</div>
+      <div class="content"><pre>code</pre></div>
+    </div>
+    <table id="t" class="tableblock frame-all grid-all stretch highlight">
+      <caption class="title">Table 1. Data</caption>
+      <colgroup><col style="width: 100%;"></colgroup>
+      <tbody>
+        <tr>
+          <td class="tableblock halign-left valign-top"><p class="tableblock">a</p></td>
+        </tr>
+      </tbody>
+    </table>
+    <div id="i" class="imageblock highlight">
+      <div class="content"><img src="cat.png" alt="cat"></div>
+      <div class="title">Figure 1. Cat</div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  block_anchor_merges_w_attr_list,
+  adoc! {r#"
+    See <<l>> and <<e>>.
+
+    [[l,Rust Code]]
+    [source,rust]
+    ----
+    fn main() {}
+    ----
+
+    [[e]]
+    [.fancy]
+    .An example
+    ====
+    foo
+    ====
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>See <a href="#l">Rust Code</a> and <a href="#e">An example</a>.</p>
+    </div>
+    <div id="l" class="listingblock">
+      <div class="content">
+        <pre class="highlight"><code class="language-rust" data-lang="rust" translate="no">fn main() {}</code></pre>
+      </div>
+    </div>
+    <div id="e" class="exampleblock fancy">
+      <div class="title">Example 1. An example</div>
+      <div class="content">
+        <div class="paragraph"><p>foo</p></div>
+      </div>
+    </div>
+  "##}
+);
+
+assert_html!(
+  xrefstyle_full_example,
+  adoc! {r#"
+    :xrefstyle: full
+
+    See <<ex>> and <<other>>.
+
+    [%collapsible]
+    .Details
+    ====
+    not numbered
+    ====
+
+    [#ex]
+    .Demo
+    ====
+    foo
+    ====
+
+    [#other,caption="Exhibit A: "]
+    .Custom
+    ====
+    bar
+    ====
+  "#},
+  contains:
+    r##"See <a href="#ex">Example 1, &#8220;Demo&#8221;</a> and <a href="#other">Custom</a>."##,
+    r#"<div class="title">Example 1. Demo</div>"#,
+    r#"<div class="title">Exhibit A: Custom</div>"#,
+);
+
+assert_html!(
+  hidden_blocks_not_numbered,
+  adoc! {r#"
+    :xrefstyle: short
+
+    [.hidden]
+    .Hidden
+    image::a.png[]
+
+    [.hidden]
+    ====
+    .Nested
+    image::b.png[]
+    ====
+
+    [#shown]
+    .Shown
+    image::c.png[]
+
+    See <<shown>>.
+  "#},
+  html! {r##"
+    <div id="shown" class="imageblock">
+      <div class="content"><img src="c.png" alt="c"></div>
+      <div class="title">Figure 1. Shown</div>
+    </div>
+    <div class="paragraph">
+      <p>See <a href="#shown">Figure 1</a>.</p>
+    </div>
+  "##}
+);
+
+assert_html!(
+  hidden_block_anchor_still_resolves,
+  adoc! {r#"
+    See <<secret>>.
+
+    [#secret.hidden]
+    .Secret Stuff
+    ====
+    Not rendered.
+    ====
+
+    [role=hidden]
+    Also not rendered.
+
+    Visible.
+  "#},
+  html! {r##"
+    <div class="paragraph">
+      <p>See <a href="#secret">Secret Stuff</a>.</p>
+    </div>
+    <div class="paragraph"><p>Visible.</p></div>
+  "##}
+);
+
+assert_html!(
+  duplicate_id_xref_uses_first_declaration,
+  adoc! {r#"
+    See <<dupe>>.
+
+    [#dupe]
+    == First
+
+    [#dupe]
+    == Second
+  "#},
+  contains: r##"See <a href="#dupe">First</a>."##
+);
@@ -19,6 +19,7 @@ mod eval_psv_tables;
 mod eval_sections;
 mod eval_source;
 mod eval_standalone;
+mod eval_stem;
 mod eval_toc;
 mod eval_verses;
 mod eval_xrefs;
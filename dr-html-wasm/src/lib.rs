@@ -1,33 +1,190 @@
 mod utils;
 
+use std::collections::HashMap;
+use std::convert::TryFrom;
+use std::str::FromStr;
+
 use asciidork_dr_html_backend as backend;
-use asciidork_meta::JobSettings;
+use asciidork_meta::{DocType, JobAttr, JobAttrs, JobSettings, SafeMode};
 use asciidork_parser::{parser::ParseResult, prelude::*};
+use serde::{Deserialize, Serialize};
 use wasm_bindgen::prelude::*;
 
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertResult {
+  success: bool,
+  html: String,
+  errors: Vec<String>,
+  /// one per error, in the same order
+  source_locations: Vec<ErrorLocation>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorLocation {
+  line: u32,
+  column: u32,
+  width: u32,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ConvertOptions {
+  #[serde(default = "default_embedded")]
+  embedded: bool,
+  safe_mode: Option<String>,
+  doctype: Option<String>,
+  #[serde(default)]
+  attributes: HashMap<String, AttrOption>,
+}
+
+fn default_embedded() -> bool {
+  true
+}
+
+/// `true` sets an attribute, `false` unsets it, a string gives it a value
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum AttrOption {
+  Bool(bool),
+  String(String),
+}
+
+impl TryFrom<ConvertOptions> for JobSettings {
+  type Error = String;
+  fn try_from(options: ConvertOptions) -> Result<Self, Self::Error> {
+    let mut settings = JobSettings {
+      embedded: options.embedded,
+      job_attrs: JobAttrs::empty(),
+      ..JobSettings::default()
+    };
+    if let Some(safe_mode) = options.safe_mode {
+      settings.safe_mode = SafeMode::from_str(&safe_mode)?;
+    }
+    if let Some(doctype) = options.doctype {
+      settings.doctype = Some(DocType::from_str(&doctype)?);
+    }
+    for (key, value) in options.attributes {
+      let attr = match value {
+        AttrOption::Bool(value) => JobAttr::readonly(value),
+        AttrOption::String(value) => JobAttr::readonly(value),
+      };
+      settings.job_attrs.insert(key, attr)?;
+    }
+    Ok(settings)
+  }
+}
+
 #[wasm_bindgen]
 pub fn convert(adoc: &str) -> String {
+  convert_with_settings(adoc, JobSettings::embedded())
+}
+
+/// `options_json` is an object with optional `embedded` (default `true`),
+/// `safeMode`, `doctype` and `attributes` keys
+#[wasm_bindgen]
+pub fn convert_with_options(adoc: &str, options_json: &str) -> String {
+  let settings = serde_json::from_str::<ConvertOptions>(options_json)
+    .map_err(|err| format!("Invalid options: {err}"))
+    .and_then(JobSettings::try_from);
+  match settings {
+    Ok(settings) => convert_with_settings(adoc, settings),
+    Err(error) => {
+      let result = ConvertResult {
+        errors: vec![error],
+        ..ConvertResult::default()
+      };
+      serde_json::to_string(&result).unwrap()
+    }
+  }
+}
+
+fn convert_with_settings(adoc: &str, settings: JobSettings) -> String {
   let bump = &Bump::new();
   let mut parser = Parser::from_str(adoc, SourceFile::Tmp, bump);
-  parser.apply_job_settings(JobSettings::embedded());
-  let result = parser.parse();
-  match result {
-    Ok(ParseResult { document, .. }) => {
-      let html = backend::convert(document).unwrap();
-      format!(
-        r#"{{"success":true,"html":"{}"}}"#,
-        html.replace('"', "\\\"").replace('\n', "\\n")
-      )
-    }
-    Err(diagnostics) => format!(
-      r#"{{"success":false,"errors":["{}"]}}"#,
-      diagnostics
+  parser.apply_job_settings(settings);
+  let result = match parser.parse() {
+    Ok(ParseResult { document, .. }) => ConvertResult {
+      success: true,
+      html: backend::convert(document).unwrap(),
+      ..ConvertResult::default()
+    },
+    Err(diagnostics) => ConvertResult {
+      errors: diagnostics.iter().map(Diagnostic::plain_text).collect(),
+      source_locations: diagnostics
         .iter()
-        .map(Diagnostic::plain_text)
-        .collect::<Vec<_>>()
-        .join(r#"",""#)
-        .replace('"', "\\\"")
-        .replace('\n', "\\n")
-    ),
+        .map(|diagnostic| ErrorLocation {
+          line: diagnostic.line_num,
+          column: diagnostic.underline_start + 1,
+          width: diagnostic.underline_width,
+        })
+        .collect(),
+      ..ConvertResult::default()
+    },
+  };
+  serde_json::to_string(&result).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use serde_json::Value;
+
+  #[test]
+  fn convert_escapes_json() {
+    let json = convert("tab\there\u{2028}sep \"quoted\"\n\n----\nback\\slash\n----");
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["success"], true);
+    assert_eq!(value["errors"], Value::Array(vec![]));
+    assert_eq!(value["sourceLocations"], Value::Array(vec![]));
+    let html = value["html"].as_str().unwrap();
+    assert!(html.contains("<p>tab\there\u{2028}sep \"quoted\"</p>"));
+    assert!(html.contains("<pre>back\\slash</pre>"));
+  }
+
+  #[test]
+  fn convert_reports_errors() {
+    let json = convert("[#a#b]\nfoo\n");
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["success"], false);
+    assert_eq!(value["html"], "");
+    let errors = value["errors"].as_array().unwrap();
+    let locations = value["sourceLocations"].as_array().unwrap();
+    assert!(!errors.is_empty());
+    assert_eq!(errors.len(), locations.len());
+    assert_eq!(locations[0]["line"], 1);
+  }
+
+  #[test]
+  fn convert_with_options_applies_attributes() {
+    let adoc = "NOTE: heads up";
+    let plain: Value = serde_json::from_str(&convert(adoc)).unwrap();
+    assert!(plain["html"]
+      .as_str()
+      .unwrap()
+      .contains(r#"<div class="title">Note</div>"#));
+    let json = convert_with_options(adoc, r#"{"attributes":{"icons":"font"}}"#);
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["success"], true);
+    let html = value["html"].as_str().unwrap();
+    assert!(html.contains(r#"<i class="fa icon-note" title="Note"></i>"#));
+    assert!(!html.contains("<!DOCTYPE html>"));
+  }
+
+  #[test]
+  fn convert_with_options_standalone_and_invalid() {
+    let json = convert_with_options("foo", r#"{"embedded":false,"doctype":"book"}"#);
+    let value: Value = serde_json::from_str(&json).unwrap();
+    let html = value["html"].as_str().unwrap();
+    assert!(html.starts_with("<!DOCTYPE html>"));
+    assert!(html.contains(r#"<body class="book">"#));
+
+    let json = convert_with_options("foo", r#"{"safeMode":"reckless"}"#);
+    let value: Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["success"], false);
+    assert!(value["errors"][0]
+      .as_str()
+      .unwrap()
+      .contains("Invalid safe mode"));
   }
 }
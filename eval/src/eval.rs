@@ -1,3 +1,6 @@
+use std::error::Error;
+use std::io::Write;
+
 use crate::internal::*;
 
 pub fn eval<B: Backend>(document: &Document, mut backend: B) -> Result<B::Output, B::Error> {
@@ -5,7 +8,36 @@ pub fn eval<B: Backend>(document: &Document, mut backend: B) -> Result<B::Output
   backend.into_result()
 }
 
+/// evaluates the document, writing output to `writer` as it is produced,
+/// see `Backend::take_flushable_output`, rather than buffering it all
+pub fn eval_to_writer<B, W>(
+  document: &Document,
+  mut backend: B,
+  writer: &mut W,
+) -> Result<(), Box<dyn Error>>
+where
+  B: Backend<Output = String>,
+  B::Error: Error + 'static,
+  W: Write,
+{
+  let mut written = Ok(());
+  visit_flushing(document, &mut backend, &mut |backend: &mut B| {
+    if written.is_ok() {
+      if let Some(output) = backend.take_flushable_output() {
+        written = writer.write_all(output.as_bytes());
+      }
+    }
+  });
+  written?;
+  writer.write_all(backend.into_result()?.as_bytes())?;
+  Ok(())
+}
+
 pub fn visit<B: Backend>(doc: &Document, backend: &mut B) {
+  visit_flushing(doc, backend, &mut |_| {});
+}
+
+fn visit_flushing<B: Backend>(doc: &Document, backend: &mut B, flush: &mut impl FnMut(&mut B)) {
   backend.enter_document(doc);
   backend.enter_header();
   if let Some(title) = &doc.title {
@@ -21,32 +53,57 @@ pub fn visit<B: Backend>(doc: &Document, backend: &mut B) {
     &[TocPosition::Auto, TocPosition::Left, TocPosition::Right],
     backend,
   );
-  eval_doc_content(doc, &doc.content, backend);
+  eval_doc_content(doc, &doc.content, backend, flush);
   backend.enter_footer();
   backend.exit_footer();
   backend.exit_document(doc);
 }
 
-fn eval_doc_content(doc: &Document, content: &DocContent, backend: &mut impl Backend) {
+/// visits a single block, outside of the normal document structure
+pub fn visit_block<B: Backend>(block: &Block, doc: &Document, backend: &mut B) {
+  backend.enter_document(doc);
+  eval_block(block, doc, backend);
+  backend.exit_document(doc);
+}
+
+fn eval_doc_content<B: Backend>(
+  doc: &Document,
+  content: &DocContent,
+  backend: &mut B,
+  flush: &mut impl FnMut(&mut B),
+) {
   backend.enter_content();
   match content {
     DocContent::Blocks(blocks) => {
-      blocks.iter().for_each(|b| eval_block(b, doc, backend));
+      blocks.iter().for_each(|b| {
+        eval_block(b, doc, backend);
+        flush(backend);
+      });
     }
     DocContent::Sectioned { sections, preamble } => {
       if let Some(blocks) = preamble {
         backend.enter_preamble(blocks);
-        blocks.iter().for_each(|b| eval_block(b, doc, backend));
+        blocks.iter().for_each(|b| {
+          eval_block(b, doc, backend);
+          flush(backend);
+        });
         backend.exit_preamble(blocks);
         eval_toc_at(doc, &[TocPosition::Preamble], backend);
       }
-      sections.iter().for_each(|s| eval_section(s, doc, backend));
+      sections
+        .iter()
+        .for_each(|s| eval_section(s, doc, backend, flush));
     }
   }
   backend.exit_content();
 }
 
-fn eval_section(section: &Section, doc: &Document, backend: &mut impl Backend) {
+fn eval_section<B: Backend>(
+  section: &Section,
+  doc: &Document,
+  backend: &mut B,
+  flush: &mut impl FnMut(&mut B),
+) {
   backend.enter_section(section);
   backend.enter_section_heading(section);
   section
@@ -54,15 +111,24 @@ fn eval_section(section: &Section, doc: &Document, backend: &mut impl Backend) {
     .iter()
     .for_each(|node| eval_inline(node, doc, backend));
   backend.exit_section_heading(section);
-  section
-    .blocks
-    .iter()
-    .for_each(|block| eval_block(block, doc, backend));
+  section.blocks.iter().for_each(|block| {
+    eval_block(block, doc, backend);
+    flush(backend);
+  });
   backend.exit_section(section);
 }
 
 fn eval_block(block: &Block, doc: &Document, backend: &mut impl Backend) {
-  if let Some(title) = &block.meta.title {
+  // NB: hidden blocks are parsed (so their anchors resolve) but not emitted
+  if block.has_role("hidden") {
+    return;
+  }
+  // NB: these blocks have no output of their own, so nothing would consume a title
+  let renders_title = !matches!(
+    block.context,
+    Context::Comment | Context::TableOfContents | Context::DocumentAttributeDecl
+  );
+  if let Some(title) = block.meta.title.as_ref().filter(|_| renders_title) {
     backend.enter_block_title(title, block);
     title.iter().for_each(|n| eval_inline(n, doc, backend));
     backend.exit_block_title(title, block);
@@ -156,10 +222,33 @@ fn eval_block(block: &Block, doc: &Document, backend: &mut impl Backend) {
       children.iter().for_each(|n| eval_inline(n, doc, backend));
       backend.exit_admonition_block(kind, block);
     }
+    (
+      Context::AdmonitionTip
+      | Context::AdmonitionNote
+      | Context::AdmonitionCaution
+      | Context::AdmonitionWarning
+      | Context::AdmonitionImportant,
+      Content::Compound(blocks),
+    ) => {
+      let kind = AdmonitionKind::try_from(block.context).unwrap();
+      backend.enter_admonition_block(kind, block);
+      backend.enter_compound_block_content(blocks, block);
+      blocks.iter().for_each(|b| eval_block(b, doc, backend));
+      backend.exit_compound_block_content(blocks, block);
+      backend.exit_admonition_block(kind, block);
+    }
     (Context::Image, Content::Empty(EmptyMetadata::Image { target, attrs })) => {
       backend.enter_image_block(target, attrs, block);
       backend.exit_image_block(block);
     }
+    (Context::Audio, Content::Empty(EmptyMetadata::Audio { target, attrs })) => {
+      backend.enter_audio_block(target, attrs, block);
+      backend.exit_audio_block(block);
+    }
+    (Context::Video, Content::Empty(EmptyMetadata::Video { target, attrs })) => {
+      backend.enter_video_block(target, attrs, block);
+      backend.exit_video_block(block);
+    }
     (Context::DocumentAttributeDecl, Content::DocumentAttribute(name, entry)) => {
       backend.visit_document_attribute_decl(name, entry);
     }
@@ -224,7 +313,7 @@ fn eval_block(block: &Block, doc: &Document, backend: &mut impl Backend) {
       backend.exit_callout_list(block, items, *depth);
     }
     (Context::Section, Content::Section(section)) => {
-      eval_section(section, doc, backend);
+      eval_section(section, doc, backend, &mut |_| {});
     }
     (Context::Literal, Content::Simple(children)) => {
       backend.enter_literal_block(block, &block.content);
@@ -240,6 +329,13 @@ fn eval_block(block: &Block, doc: &Document, backend: &mut impl Backend) {
       backend.exit_simple_block_content(children, block);
       backend.exit_passthrough_block(block, &block.content);
     }
+    (Context::Stem, Content::Simple(children)) => {
+      backend.enter_stem_block(block, &block.content);
+      backend.enter_simple_block_content(children, block);
+      children.iter().for_each(|n| eval_inline(n, doc, backend));
+      backend.exit_simple_block_content(children, block);
+      backend.exit_stem_block(block, &block.content);
+    }
     (Context::Table, Content::Table(table)) => {
       backend.enter_table(table, block);
       if let Some(header_row) = &table.header_row {
@@ -333,6 +429,12 @@ fn eval_inline(inline: &InlineNode, doc: &Document, backend: &mut impl Backend)
     LitMono(text) => backend.visit_inline_lit_mono(text),
     CurlyQuote(kind) => backend.visit_curly_quote(*kind),
     MultiCharWhitespace(ws) => backend.visit_multichar_whitespace(ws.as_str()),
+    Macro(Footnote { id: Some(id), text, .. }) if text.is_empty() => {
+      match doc.footnotes.borrow().get(&id.src) {
+        Some(number) => backend.visit_footnote_reference(*number, id),
+        None => backend.visit_missing_footnote_reference(id),
+      }
+    }
     Macro(Footnote { number, id, text }) => {
       backend.enter_footnote(*number, id.as_deref(), text);
       text.iter().for_each(|node| eval_inline(node, doc, backend));
@@ -340,6 +442,7 @@ fn eval_inline(inline: &InlineNode, doc: &Document, backend: &mut impl Backend)
     }
     Macro(Image { target, attrs, .. }) => backend.visit_image_macro(target, attrs),
     Macro(Button(text)) => backend.visit_button_macro(text),
+    Macro(Stem { name, text }) => backend.visit_stem_macro(name, text),
     Macro(Link { target, attrs, scheme, caret }) => {
       if let Some(Some(nodes)) = attrs.as_ref().and_then(|a| a.positional.first()) {
         backend.enter_link_macro(target, attrs.as_ref(), *scheme, true, *caret);
@@ -357,11 +460,35 @@ fn eval_inline(inline: &InlineNode, doc: &Document, backend: &mut impl Backend)
       backend.visit_menu_macro(&items.iter().map(|s| s.src.as_str()).collect::<Vec<&str>>())
     }
     Macro(Xref { id, linktext }) => {
+      if let Some(target) = InterdocTarget::parse(id) {
+        let linktext = linktext.as_ref().map(|t| t.as_slice());
+        backend.enter_interdoc_xref(target, linktext);
+        if let Some(nodes) = linktext {
+          nodes
+            .iter()
+            .for_each(|node| eval_inline(node, doc, backend));
+        }
+        backend.exit_interdoc_xref(target, linktext);
+        return;
+      }
       backend.enter_xref(id, linktext.as_ref().map(|t| t.as_slice()));
-      if let Some(text) = doc
-        .anchors
-        .borrow()
-        .get(&id.src)
+      let anchors = doc.anchors.borrow();
+      let anchor = anchors.get(&id.src);
+      if let Some((prefix, anchor)) = anchor
+        .filter(|_| linktext.is_none())
+        .and_then(|anchor| xref_caption_prefix(anchor, doc).map(|prefix| (prefix, anchor)))
+      {
+        visit_text_w_specialchars(&prefix, backend);
+        if doc.meta.str("xrefstyle") == Some("full") && !anchor.title.is_empty() {
+          backend.visit_inline_text(", ");
+          backend.enter_inline_quote(QuoteKind::Double, &anchor.title);
+          anchor
+            .title
+            .iter()
+            .for_each(|node| eval_inline(node, doc, backend));
+          backend.exit_inline_quote(QuoteKind::Double, &anchor.title);
+        }
+      } else if let Some(text) = anchor
         .map(|anchor| {
           anchor
             .reftext
@@ -394,6 +521,49 @@ fn eval_inline(inline: &InlineNode, doc: &Document, backend: &mut impl Backend)
   }
 }
 
+/// `Figure 2` style xref text for numbered targets, per `xrefstyle`,
+/// or `Equation (2)` / `(2)` for numbered equations
+// NB: `visit_inline_text` expects text already free of special chars,
+// but the caption prefix comes straight from (user-defined) attributes
+fn visit_text_w_specialchars(text: &str, backend: &mut impl Backend) {
+  let mut rest = text;
+  while let Some(idx) = rest.find(['<', '>', '&']) {
+    if idx > 0 {
+      backend.visit_inline_text(&rest[..idx]);
+    }
+    backend.visit_inline_specialchar(&match rest.as_bytes()[idx] {
+      b'<' => SpecialCharKind::LessThan,
+      b'>' => SpecialCharKind::GreaterThan,
+      _ => SpecialCharKind::Ampersand,
+    });
+    rest = &rest[idx + 1..];
+  }
+  if !rest.is_empty() {
+    backend.visit_inline_text(rest);
+  }
+}
+
+fn xref_caption_prefix(anchor: &Anchor, doc: &Document) -> Option<String> {
+  if anchor.reftext.is_some() {
+    return None;
+  }
+  let (caption_attr, number) = anchor.numeral?;
+  let xrefstyle = doc.meta.str("xrefstyle");
+  if caption_attr == "equation-caption" {
+    return match xrefstyle {
+      Some("full") => Some(format!("{} ({number})", doc.meta.str(caption_attr)?)),
+      Some("short") => Some(format!("({number})")),
+      _ if anchor.title.is_empty() => Some(format!("({number})")),
+      _ => None,
+    };
+  }
+  if !matches!(xrefstyle, Some("full" | "short")) {
+    return None;
+  }
+  let caption = doc.meta.str(caption_attr)?;
+  Some(format!("{caption} {number}"))
+}
+
 fn eval_table_row(row: &Row, section: TableSection, doc: &Document, backend: &mut impl Backend) {
   backend.enter_table_row(row, section);
   row.cells.iter().for_each(|cell| {
@@ -0,0 +1,983 @@
+use std::fmt::Write;
+
+use crate::internal::*;
+
+/// GitHub-flavored Markdown output, constructs without a markdown
+/// equivalent (open blocks, sidebars, etc.) degrade to their content
+#[derive(Debug, Default)]
+pub struct Markdown {
+  md: String,
+  buffers: Vec<String>,
+  doc_meta: DocumentMeta,
+  block_title: Option<String>,
+  newlines: Newlines,
+  default_newlines: Newlines,
+  verbatim: u8,
+  in_asciidoc_table_cell: bool,
+  lists: Vec<ListState>,
+  footnotes: Vec<(u16, String)>,
+  table_rows: Vec<Vec<String>>,
+  table_row: Vec<String>,
+  table_aligns: Vec<&'static str>,
+}
+
+impl Backend for Markdown {
+  type Output = String;
+  type Error = Infallible;
+
+  fn enter_document(&mut self, document: &Document) {
+    let parent_meta = mem::replace(&mut self.doc_meta, document.meta.clone());
+    if self.in_asciidoc_table_cell {
+      self.doc_meta.inherit_doc_attrs(&parent_meta);
+    }
+    if self.doc_meta.is_true("hardbreaks-option") {
+      self.default_newlines = Newlines::JoinWithBreak;
+      self.newlines = Newlines::JoinWithBreak;
+    }
+  }
+
+  fn exit_document(&mut self, _document: &Document) {
+    if !self.in_asciidoc_table_cell && !self.footnotes.is_empty() {
+      self.start_block();
+      for (idx, (number, text)) in mem::take(&mut self.footnotes).iter().enumerate() {
+        if idx > 0 {
+          self.push_ch('\n');
+        }
+        write!(self.md, "[^{number}]: {text}").unwrap();
+      }
+    }
+    self.md.truncate(self.md.trim_end().len());
+    let inline = self.doc_meta.get_doctype() == DocType::Inline;
+    if !self.md.is_empty() && !inline && !self.in_asciidoc_table_cell {
+      self.push_ch('\n');
+    }
+  }
+
+  fn enter_header(&mut self) {}
+  fn exit_header(&mut self) {}
+  fn enter_content(&mut self) {}
+  fn exit_content(&mut self) {}
+  fn enter_footer(&mut self) {}
+  fn exit_footer(&mut self) {}
+
+  fn visit_document_attribute_decl(&mut self, name: &str, value: &AttrValue) {
+    if name == "hardbreaks-option" {
+      self.default_newlines = if value.is_true() {
+        Newlines::JoinWithBreak
+      } else {
+        Newlines::JoinWithSpace
+      };
+      self.newlines = self.default_newlines;
+    }
+    _ = self.doc_meta.insert_doc_attr(name, value.clone());
+  }
+
+  fn enter_preamble(&mut self, _blocks: &[Block]) {}
+  fn exit_preamble(&mut self, _blocks: &[Block]) {}
+
+  fn enter_document_title(&mut self, _nodes: &[InlineNode]) {
+    if self.standalone() {
+      self.start_block();
+      self.push_str("# ");
+    } else {
+      self.start_buffering();
+    }
+  }
+
+  fn exit_document_title(&mut self, _nodes: &[InlineNode]) {
+    if !self.standalone() {
+      self.take_buffer();
+    }
+  }
+
+  // NB: markdown renderers generate their own toc, if any
+  fn enter_toc(&mut self, _toc: &TableOfContents) {
+    self.start_buffering();
+  }
+
+  fn exit_toc(&mut self, _toc: &TableOfContents) {
+    self.take_buffer();
+  }
+
+  fn enter_section(&mut self, _section: &Section) {}
+  fn exit_section(&mut self, _section: &Section) {}
+
+  fn enter_section_heading(&mut self, section: &Section) {
+    self.start_heading(section.level);
+  }
+
+  fn exit_section_heading(&mut self, _section: &Section) {}
+
+  fn enter_paragraph_block(&mut self, _block: &Block) {
+    self.push_block_title();
+    self.start_block();
+  }
+
+  fn exit_paragraph_block(&mut self, _block: &Block) {}
+
+  fn enter_sidebar_block(&mut self, _block: &Block, content: &BlockContent) {
+    self.open_degraded_block(content);
+  }
+
+  fn exit_sidebar_block(&mut self, _block: &Block, _content: &BlockContent) {}
+
+  fn enter_open_block(&mut self, _block: &Block, content: &BlockContent) {
+    self.open_degraded_block(content);
+  }
+
+  fn exit_open_block(&mut self, _block: &Block, _content: &BlockContent) {}
+
+  fn enter_example_block(&mut self, _block: &Block, content: &BlockContent) {
+    self.open_degraded_block(content);
+  }
+
+  fn exit_example_block(&mut self, _block: &Block, _content: &BlockContent) {}
+
+  fn enter_quote_block(&mut self, _block: &Block, content: &BlockContent) {
+    self.start_buffering();
+    self.open_degraded_block(content);
+  }
+
+  fn exit_quote_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.push_attribution(block);
+    self.close_blockquote();
+  }
+
+  fn enter_verse_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.start_buffering();
+    self.push_block_title();
+    self.start_block();
+  }
+
+  fn exit_verse_block(&mut self, block: &Block, _content: &BlockContent) {
+    self.push_attribution(block);
+    self.close_blockquote();
+  }
+
+  fn enter_listing_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.open_fenced_block();
+  }
+
+  fn exit_listing_block(&mut self, block: &Block, _content: &BlockContent) {
+    let lang = self.source_lang(block);
+    self.close_fenced_block(lang.as_deref().unwrap_or(""));
+  }
+
+  fn enter_literal_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.open_fenced_block();
+  }
+
+  fn exit_literal_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.close_fenced_block("");
+  }
+
+  fn enter_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.start_block();
+  }
+
+  fn exit_passthrough_block(&mut self, _block: &Block, _content: &BlockContent) {}
+
+  fn enter_stem_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.open_fenced_block();
+  }
+
+  fn exit_stem_block(&mut self, _block: &Block, _content: &BlockContent) {
+    self.close_fenced_block("math");
+  }
+
+  fn enter_image_block(&mut self, target: &str, attrs: &AttrList, _block: &Block) {
+    self.push_block_title();
+    self.start_block();
+    self.push_image(target, attrs);
+  }
+
+  fn exit_image_block(&mut self, _block: &Block) {}
+
+  fn enter_audio_block(&mut self, target: &str, _attrs: &AttrList, _block: &Block) {
+    self.push_block_title();
+    self.start_block();
+    self.push_media_link(target);
+  }
+
+  fn exit_audio_block(&mut self, _block: &Block) {}
+
+  fn enter_video_block(&mut self, target: &str, _attrs: &AttrList, _block: &Block) {
+    self.push_block_title();
+    self.start_block();
+    self.push_media_link(target);
+  }
+
+  fn exit_video_block(&mut self, _block: &Block) {}
+
+  fn enter_admonition_block(&mut self, kind: AdmonitionKind, block: &Block) {
+    self.start_buffering();
+    self.push_block_title();
+    self.start_block();
+    self.push(["**", &kind.lowercase_str().to_uppercase(), ":**"]);
+    if let BlockContent::Simple(_) = block.content {
+      self.push_ch(' ');
+    }
+  }
+
+  fn exit_admonition_block(&mut self, _kind: AdmonitionKind, _block: &Block) {
+    self.close_blockquote();
+  }
+
+  fn enter_quoted_paragraph(&mut self, _block: &Block, _attr: &str, _cite: Option<&str>) {
+    self.start_buffering();
+    self.push_block_title();
+    self.start_block();
+  }
+
+  fn exit_quoted_paragraph(&mut self, _block: &Block, attr: &str, cite: Option<&str>) {
+    self.start_block();
+    self.push(["— ", attr]);
+    if let Some(cite) = cite {
+      self.push([", ", cite]);
+    }
+    self.close_blockquote();
+  }
+
+  fn enter_discrete_heading(&mut self, level: u8, _id: Option<&str>, _block: &Block) {
+    self.start_heading(level);
+  }
+
+  fn exit_discrete_heading(&mut self, _level: u8, _id: Option<&str>, _block: &Block) {}
+
+  fn enter_unordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.open_list(ListVariant::Unordered, 1);
+  }
+
+  fn exit_unordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.lists.pop();
+  }
+
+  fn enter_ordered_list(&mut self, block: &Block, items: &[ListItem], _depth: u8) {
+    let start = block
+      .named_attr("start")
+      .and_then(|start| start.parse().ok())
+      .or_else(|| match items.first().map(|item| item.marker) {
+        Some(ListMarker::Digits(n)) => Some(n),
+        _ => None,
+      });
+    self.open_list(ListVariant::Ordered, start.unwrap_or(1));
+  }
+
+  fn exit_ordered_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.lists.pop();
+  }
+
+  fn enter_callout_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.open_list(ListVariant::Callout, 1);
+  }
+
+  fn exit_callout_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.lists.pop();
+  }
+
+  fn enter_description_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.open_list(ListVariant::Description, 1);
+  }
+
+  fn exit_description_list(&mut self, _block: &Block, _items: &[ListItem], _depth: u8) {
+    self.lists.pop();
+  }
+
+  fn enter_description_list_term(&mut self, _item: &ListItem) {
+    self.start_buffering();
+    self.push_str("**");
+  }
+
+  fn exit_description_list_term(&mut self, _item: &ListItem) {
+    self.push_str("**");
+  }
+
+  fn enter_description_list_description(&mut self, _blocks: &[Block], _item: &ListItem) {}
+
+  fn exit_description_list_description(&mut self, _blocks: &[Block], item: &ListItem) {
+    self.close_list_item(item);
+  }
+
+  fn enter_list_item_principal(&mut self, item: &ListItem, _variant: ListVariant) {
+    self.start_buffering();
+    if let ListItemTypeMeta::Checklist(checked, _) = &item.type_meta {
+      self.push_str(if *checked { "[x]" } else { "[ ]" });
+    }
+  }
+
+  fn exit_list_item_principal(&mut self, _item: &ListItem, _variant: ListVariant) {}
+
+  fn enter_list_item_blocks(&mut self, _blocks: &[Block], _item: &ListItem, _: ListVariant) {}
+
+  fn exit_list_item_blocks(&mut self, _blocks: &[Block], item: &ListItem, _: ListVariant) {
+    self.close_list_item(item);
+  }
+
+  fn enter_table(&mut self, _table: &Table, _block: &Block) {
+    self.push_block_title();
+    self.table_rows.clear();
+    self.table_aligns.clear();
+  }
+
+  fn exit_table(&mut self, table: &Table, _block: &Block) {
+    let mut rows = mem::take(&mut self.table_rows);
+    let cols = rows
+      .iter()
+      .map(Vec::len)
+      .chain([table.col_widths.len()])
+      .max()
+      .unwrap_or(0);
+    // NB: gfm tables require a header row, so we supply an empty one if needed
+    let header = if table.header_row.is_some() && !rows.is_empty() {
+      rows.remove(0)
+    } else {
+      vec![]
+    };
+    self.start_block();
+    self.push_table_row(&header, cols);
+    self.push_str("\n|");
+    for idx in 0..cols {
+      let align = self.table_aligns.get(idx).copied().unwrap_or("---");
+      self.push([" ", align, " |"]);
+    }
+    for row in &rows {
+      self.push_ch('\n');
+      self.push_table_row(row, cols);
+    }
+  }
+
+  fn enter_table_section(&mut self, _section: TableSection) {}
+  fn exit_table_section(&mut self, _section: TableSection) {}
+
+  fn enter_table_row(&mut self, _row: &Row, _section: TableSection) {
+    self.table_row.clear();
+  }
+
+  fn exit_table_row(&mut self, _row: &Row, _section: TableSection) {
+    self.table_rows.push(mem::take(&mut self.table_row));
+  }
+
+  fn enter_table_cell(&mut self, cell: &Cell, _section: TableSection) {
+    self.start_buffering();
+    if let CellContent::Literal(_) = cell.content {
+      self.verbatim += 1;
+    }
+  }
+
+  fn exit_table_cell(&mut self, cell: &Cell, _section: TableSection) {
+    let mut text = self.take_buffer();
+    if let CellContent::Literal(_) = cell.content {
+      self.verbatim -= 1;
+      text = code_span(text.trim());
+    }
+    // NB: pipe table cells must fit on a single line
+    let text = text
+      .trim()
+      .replace("\\\n", "\n")
+      .replace('\n', "<br>")
+      .replace('|', "\\|");
+    if self.table_rows.is_empty() {
+      let align = match cell.h_align {
+        HorizontalAlignment::Left => "---",
+        HorizontalAlignment::Center => ":---:",
+        HorizontalAlignment::Right => "---:",
+      };
+      self.table_aligns.push(align);
+      (1..cell.col_span).for_each(|_| self.table_aligns.push("---"));
+    }
+    self.table_row.push(text);
+    (1..cell.col_span).for_each(|_| self.table_row.push(String::new()));
+  }
+
+  fn enter_cell_paragraph(&mut self, cell: &Cell, section: TableSection) {
+    if !self.md.is_empty() {
+      self.push_str("<br><br>");
+    }
+    if section == TableSection::Header {
+      return;
+    }
+    match cell.content {
+      CellContent::Emphasis(_) => self.push_ch('_'),
+      CellContent::Header(_) | CellContent::Strong(_) => self.push_str("**"),
+      CellContent::Monospace(_) => {
+        self.push_ch('`');
+        self.verbatim += 1;
+      }
+      _ => {}
+    }
+  }
+
+  fn exit_cell_paragraph(&mut self, cell: &Cell, section: TableSection) {
+    if section == TableSection::Header {
+      return;
+    }
+    match cell.content {
+      CellContent::Emphasis(_) => self.push_ch('_'),
+      CellContent::Header(_) | CellContent::Strong(_) => self.push_str("**"),
+      CellContent::Monospace(_) => {
+        self.verbatim -= 1;
+        self.push_ch('`');
+      }
+      _ => {}
+    }
+  }
+
+  fn asciidoc_table_cell_backend(&mut self) -> Self {
+    Self {
+      in_asciidoc_table_cell: true,
+      doc_meta: self.doc_meta.clone(),
+      ..Self::default()
+    }
+  }
+
+  fn visit_asciidoc_table_cell_result(&mut self, result: Result<Self::Output, Self::Error>) {
+    self.md.push_str(&result.unwrap());
+  }
+
+  fn enter_block_title(&mut self, _title: &[InlineNode], _block: &Block) {
+    self.start_buffering();
+  }
+
+  fn exit_block_title(&mut self, _title: &[InlineNode], _block: &Block) {
+    self.block_title = Some(self.take_buffer());
+  }
+
+  fn enter_simple_block_content(&mut self, _children: &[InlineNode], block: &Block) {
+    match block.context {
+      BlockContext::Listing
+      | BlockContext::Literal
+      | BlockContext::Passthrough
+      | BlockContext::Stem => self.newlines = Newlines::Preserve,
+      BlockContext::Verse => self.newlines = Newlines::JoinWithBreak,
+      _ if block.has_attr_option("hardbreaks") => self.newlines = Newlines::JoinWithBreak,
+      _ => {}
+    }
+  }
+
+  fn exit_simple_block_content(&mut self, _children: &[InlineNode], _block: &Block) {
+    self.newlines = self.default_newlines;
+  }
+
+  fn enter_compound_block_content(&mut self, _children: &[Block], _block: &Block) {}
+  fn exit_compound_block_content(&mut self, _children: &[Block], _block: &Block) {}
+
+  fn visit_thematic_break(&mut self, _block: &Block) {
+    self.start_block();
+    self.push_str("---");
+  }
+
+  fn visit_page_break(&mut self, _block: &Block) {}
+
+  fn visit_inline_text(&mut self, text: &str) {
+    if self.verbatim > 0 {
+      self.push_str(text);
+    } else {
+      self.push_escaped(text);
+    }
+  }
+
+  fn visit_inline_lit_mono(&mut self, text: &str) {
+    self.push_str(&code_span(text));
+  }
+
+  fn visit_joining_newline(&mut self) {
+    match self.newlines {
+      Newlines::JoinWithSpace => self.push_ch(' '),
+      Newlines::JoinWithBreak => self.push_str("\\\n"),
+      Newlines::Preserve => self.push_ch('\n'),
+    }
+  }
+
+  fn visit_curly_quote(&mut self, kind: CurlyKind) {
+    match kind {
+      CurlyKind::LeftDouble => self.push_ch('”'),
+      CurlyKind::RightDouble => self.push_ch('“'),
+      CurlyKind::LeftSingle => self.push_ch('’'),
+      CurlyKind::RightSingle => self.push_ch('‘'),
+      CurlyKind::LegacyImplicitApostrophe => self.push_ch('’'),
+    }
+  }
+
+  fn visit_multichar_whitespace(&mut self, whitespace: &str) {
+    if self.newlines == Newlines::Preserve {
+      self.push_str(whitespace);
+    } else {
+      self.push_ch(' ');
+    }
+  }
+
+  fn visit_button_macro(&mut self, text: &str) {
+    self.push_str("**");
+    self.push_escaped(text);
+    self.push_str("**");
+  }
+
+  fn visit_stem_macro(&mut self, _name: &str, text: &str) {
+    self.push(["$", text, "$"]);
+  }
+
+  fn visit_menu_macro(&mut self, items: &[&str]) {
+    self.push_str("**");
+    for (idx, item) in items.iter().enumerate() {
+      if idx > 0 {
+        self.push_str(" ▸ ");
+      }
+      self.push_escaped(item);
+    }
+    self.push_str("**");
+  }
+
+  fn visit_image_macro(&mut self, target: &str, attrs: &AttrList) {
+    self.push_image(target, attrs);
+  }
+
+  fn visit_keyboard_macro(&mut self, keys: &[&str]) {
+    for (idx, key) in keys.iter().enumerate() {
+      if idx > 0 {
+        self.push_ch('+');
+      }
+      self.push_str("<kbd>");
+      for c in key.chars() {
+        match c {
+          '&' => self.push_str("&amp;"),
+          '<' => self.push_str("&lt;"),
+          '>' => self.push_str("&gt;"),
+          _ => self.push_ch(c),
+        }
+      }
+      self.push_str("</kbd>");
+    }
+  }
+
+  fn enter_link_macro(
+    &mut self,
+    _target: &str,
+    _attrs: Option<&AttrList>,
+    _scheme: Option<UrlScheme>,
+    _has_link_text: bool,
+    _blank_window_shorthand: bool,
+  ) {
+    self.push_ch('[');
+  }
+
+  fn exit_link_macro(
+    &mut self,
+    target: &str,
+    _attrs: Option<&AttrList>,
+    scheme: Option<UrlScheme>,
+    has_link_text: bool,
+  ) {
+    if !has_link_text {
+      self.push_escaped(target);
+    }
+    self.push_str("](");
+    if matches!(scheme, Some(UrlScheme::Mailto)) {
+      self.push_str("mailto:");
+    }
+    self.push([target, ")"]);
+  }
+
+  fn visit_callout(&mut self, callout: Callout) {
+    if !self.md.ends_with(' ') {
+      self.push_ch(' ');
+    }
+    write!(self.md, "<{}>", callout.number).unwrap();
+  }
+
+  fn visit_callout_tuck(&mut self, comment: &str) {
+    self.push_str(comment);
+  }
+
+  fn enter_inline_italic(&mut self, _children: &[InlineNode]) {
+    self.push_delimiter("_");
+  }
+
+  fn exit_inline_italic(&mut self, _children: &[InlineNode]) {
+    self.push_delimiter("_");
+  }
+
+  fn enter_inline_mono(&mut self, _children: &[InlineNode]) {
+    self.push_ch('`');
+    self.verbatim += 1;
+  }
+
+  fn exit_inline_mono(&mut self, _children: &[InlineNode]) {
+    self.verbatim -= 1;
+    self.push_ch('`');
+  }
+
+  fn enter_inline_bold(&mut self, _children: &[InlineNode]) {
+    self.push_delimiter("**");
+  }
+
+  fn exit_inline_bold(&mut self, _children: &[InlineNode]) {
+    self.push_delimiter("**");
+  }
+
+  fn visit_inline_specialchar(&mut self, char: &SpecialCharKind) {
+    match (char, self.verbatim > 0) {
+      (SpecialCharKind::Ampersand, true) => self.push_ch('&'),
+      (SpecialCharKind::LessThan, true) => self.push_ch('<'),
+      (SpecialCharKind::GreaterThan, true) => self.push_ch('>'),
+      (SpecialCharKind::Ampersand, false) => self.push_str("&amp;"),
+      (SpecialCharKind::LessThan, false) => self.push_str("&lt;"),
+      (SpecialCharKind::GreaterThan, false) => self.push_str("&gt;"),
+    }
+  }
+
+  fn enter_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_passthrough(&mut self, _children: &[InlineNode]) {}
+
+  fn enter_inline_highlight(&mut self, _children: &[InlineNode]) {}
+  fn exit_inline_highlight(&mut self, _children: &[InlineNode]) {}
+
+  fn enter_inline_subscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("<sub>");
+  }
+
+  fn exit_inline_subscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("</sub>");
+  }
+
+  fn enter_inline_superscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("<sup>");
+  }
+
+  fn exit_inline_superscript(&mut self, _children: &[InlineNode]) {
+    self.push_str("</sup>");
+  }
+
+  fn enter_inline_quote(&mut self, kind: QuoteKind, _children: &[InlineNode]) {
+    match kind {
+      QuoteKind::Double => self.push_ch('“'),
+      QuoteKind::Single => self.push_ch('‘'),
+    }
+  }
+
+  fn exit_inline_quote(&mut self, kind: QuoteKind, _children: &[InlineNode]) {
+    match kind {
+      QuoteKind::Double => self.push_ch('”'),
+      QuoteKind::Single => self.push_ch('’'),
+    }
+  }
+
+  fn enter_footnote(&mut self, number: u16, _id: Option<&str>, _content: &[InlineNode]) {
+    write!(self.md, "[^{number}]").unwrap();
+    self.start_buffering();
+  }
+
+  fn exit_footnote(&mut self, number: u16, _id: Option<&str>, _content: &[InlineNode]) {
+    let text = self.take_buffer();
+    self.footnotes.push((number, text));
+  }
+
+  fn visit_footnote_reference(&mut self, number: u16, _id: &str) {
+    write!(self.md, "[^{number}]").unwrap();
+  }
+
+  fn visit_missing_footnote_reference(&mut self, id: &str) {
+    self.push_escaped(&format!("[{id}]"));
+  }
+
+  fn enter_text_span(&mut self, _attrs: &AttrList, _children: &[InlineNode]) {}
+  fn exit_text_span(&mut self, _attrs: &AttrList, _children: &[InlineNode]) {}
+
+  fn enter_xref(&mut self, _id: &str, _target: Option<&[InlineNode]>) {
+    self.push_ch('[');
+  }
+
+  fn exit_xref(&mut self, id: &str, _target: Option<&[InlineNode]>) {
+    self.push(["](#", id, ")"]);
+  }
+
+  fn visit_missing_xref(&mut self, id: &str) {
+    self.push_escaped(&format!("[{id}]"));
+  }
+
+  fn enter_interdoc_xref(&mut self, _target: InterdocTarget, _linktext: Option<&[InlineNode]>) {
+    self.push_ch('[');
+  }
+
+  fn exit_interdoc_xref(&mut self, target: InterdocTarget, linktext: Option<&[InlineNode]>) {
+    if linktext.is_none() {
+      let path = target.out_path(&self.doc_meta, ".md");
+      self.push_escaped(&path);
+    }
+    let href = target.href(&self.doc_meta, ".md");
+    self.push(["](", &href, ")"]);
+  }
+
+  fn visit_inline_anchor(&mut self, id: &str) {
+    self.push([r#"<a id=""#, id, r#""></a>"#]);
+  }
+
+  fn visit_linebreak(&mut self) {
+    self.push_str("\\\n");
+  }
+
+  fn into_result(self) -> Result<Self::Output, Self::Error> {
+    Ok(self.md)
+  }
+
+  fn result(&self) -> Result<&Self::Output, Self::Error> {
+    Ok(&self.md)
+  }
+}
+
+impl Markdown {
+  pub fn new() -> Self {
+    Self::default()
+  }
+
+  pub fn into_string(self) -> String {
+    self.md
+  }
+
+  fn push_str(&mut self, s: &str) {
+    self.md.push_str(s);
+  }
+
+  fn push_ch(&mut self, c: char) {
+    self.md.push(c);
+  }
+
+  fn push<const N: usize>(&mut self, strs: [&str; N]) {
+    for s in strs {
+      self.push_str(s);
+    }
+  }
+
+  fn push_escaped(&mut self, s: &str) {
+    for c in s.chars() {
+      if matches!(c, '\\' | '`' | '*' | '_' | '[' | ']') {
+        self.push_ch('\\');
+      }
+      self.push_ch(c);
+    }
+  }
+
+  /// emphasis markers have no meaning inside code spans
+  fn push_delimiter(&mut self, delimiter: &str) {
+    if self.verbatim == 0 {
+      self.push_str(delimiter);
+    }
+  }
+
+  fn start_buffering(&mut self) {
+    self.buffers.push(mem::take(&mut self.md));
+  }
+
+  fn take_buffer(&mut self) -> String {
+    let parent = self.buffers.pop().unwrap_or_default();
+    mem::replace(&mut self.md, parent)
+  }
+
+  /// separates a new block from any preceding content by a blank line
+  fn start_block(&mut self) {
+    if self.md.is_empty() {
+      return;
+    }
+    self.md.truncate(self.md.trim_end_matches('\n').len());
+    self.push_str("\n\n");
+  }
+
+  fn standalone(&self) -> bool {
+    self.doc_meta.get_doctype() != DocType::Inline
+      && !self.in_asciidoc_table_cell
+      && !self.doc_meta.embedded
+  }
+
+  fn start_heading(&mut self, level: u8) {
+    self.start_block();
+    let hashes = (level as usize + 1).min(6);
+    self.push_str(&"#".repeat(hashes));
+    self.push_ch(' ');
+  }
+
+  fn push_block_title(&mut self) {
+    if let Some(title) = self.block_title.take() {
+      self.start_block();
+      self.push(["**", &title, "**"]);
+    }
+  }
+
+  fn open_degraded_block(&mut self, content: &BlockContent) {
+    self.push_block_title();
+    if let BlockContent::Simple(_) = content {
+      self.start_block();
+    }
+  }
+
+  fn push_attribution(&mut self, block: &Block) {
+    let Some(attrs) = &block.meta.attrs else {
+      return;
+    };
+    let (attribution, cite) = (attrs.str_positional_at(1), attrs.str_positional_at(2));
+    if attribution.is_none() && cite.is_none() {
+      return;
+    }
+    self.start_block();
+    self.push_str("— ");
+    self.push_str(
+      &[attribution, cite]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", "),
+    );
+  }
+
+  fn close_blockquote(&mut self) {
+    let content = self.take_buffer();
+    self.start_block();
+    for (idx, line) in content.trim_end().lines().enumerate() {
+      if idx > 0 {
+        self.push_ch('\n');
+      }
+      if line.is_empty() {
+        self.push_ch('>');
+      } else {
+        self.push(["> ", line]);
+      }
+    }
+  }
+
+  fn open_fenced_block(&mut self) {
+    self.push_block_title();
+    self.start_block();
+    self.start_buffering();
+    self.verbatim += 1;
+  }
+
+  fn close_fenced_block(&mut self, info: &str) {
+    self.verbatim -= 1;
+    let content = self.take_buffer();
+    // NB: the fence must be longer than any backtick run in the content
+    let longest_run = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let fence = "`".repeat((longest_run + 1).max(3));
+    self.push([&fence, info, "\n", &content, "\n", &fence]);
+  }
+
+  fn open_list(&mut self, variant: ListVariant, number: u16) {
+    self.push_block_title();
+    // NB: keep nested lists tight when directly following an item's principal text
+    if !self.lists.is_empty() && !self.md.is_empty() && !self.md.contains("\n\n") {
+      self.md.truncate(self.md.trim_end_matches('\n').len());
+      self.push_ch('\n');
+    } else {
+      self.start_block();
+    }
+    self.lists.push(ListState { variant, number, items: 0 });
+  }
+
+  fn close_list_item(&mut self, item: &ListItem) {
+    let content = self.take_buffer();
+    let Some(list) = self.lists.last_mut() else {
+      return;
+    };
+    let marker = match list.variant {
+      ListVariant::Ordered => format!("{}. ", list.number),
+      ListVariant::Callout => {
+        let number = item.marker.callout_num().map_or(list.number, u16::from);
+        format!("{}. ", number)
+      }
+      ListVariant::Unordered | ListVariant::Description => "- ".to_string(),
+    };
+    list.number += 1;
+    list.items += 1;
+    if list.items > 1 {
+      self.push_ch('\n');
+    }
+    let indent = " ".repeat(marker.len());
+    for (idx, line) in content.trim_end().lines().enumerate() {
+      if idx == 0 {
+        self.push([&marker, line]);
+      } else if line.is_empty() {
+        self.push_ch('\n');
+      } else {
+        self.push(["\n", &indent, line]);
+      }
+    }
+  }
+
+  fn push_table_row(&mut self, cells: &[String], cols: usize) {
+    self.push_ch('|');
+    for idx in 0..cols {
+      let cell = cells.get(idx).map(String::as_str).unwrap_or("");
+      self.push([" ", cell, " |"]);
+    }
+  }
+
+  fn push_image(&mut self, target: &str, attrs: &AttrList) {
+    let alt = if let Some(alt) = attrs.named("alt").or_else(|| attrs.str_positional_at(0)) {
+      alt.to_string()
+    } else if let Some(Some(nodes)) = attrs.positional.first() {
+      nodes.plain_text().concat()
+    } else {
+      let basename = target.rsplit(['/', '\\']).next().unwrap_or(target);
+      let stem = basename.split('.').next().unwrap_or(basename);
+      stem.replace(['-', '_'], " ")
+    };
+    self.push_str("![");
+    self.push_escaped(&alt);
+    self.push(["](", &self.media_path(target), ")"]);
+  }
+
+  fn push_media_link(&mut self, target: &str) {
+    self.push_ch('[');
+    self.push_escaped(target);
+    self.push(["](", &self.media_path(target), ")"]);
+  }
+
+  /// resolves relative targets against `imagesdir`, as a link destination
+  fn media_path(&self, target: &str) -> String {
+    let is_absolute = Path::is_absolute_or_uri(target);
+    let path = match self.doc_meta.str("imagesdir").filter(|_| !is_absolute) {
+      Some(imagesdir) => Path::new(imagesdir).join(target).to_string(),
+      None => target.to_string(),
+    };
+    // NB: bare destinations end at whitespace and can't hold unbalanced parens
+    if path.contains([' ', '\t', '(', ')']) {
+      format!("<{}>", path.replace('<', "\\<").replace('>', "\\>"))
+    } else {
+      path
+    }
+  }
+
+  fn source_lang(&self, block: &Block) -> Option<String> {
+    let attrs = block.meta.attrs.as_ref();
+    match attrs.map_or((None, None), |a| {
+      (a.str_positional_at(0), a.str_positional_at(1))
+    }) {
+      (None | Some("source"), Some(lang)) => Some(lang.to_string()),
+      (Some("source"), None) => self.doc_meta.string("source-language"),
+      _ => None,
+    }
+  }
+}
+
+#[derive(Debug)]
+struct ListState {
+  variant: ListVariant,
+  number: u16,
+  items: usize,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+enum Newlines {
+  #[default]
+  JoinWithSpace,
+  JoinWithBreak,
+  Preserve,
+}
+
+/// wraps text in a code span, w/ a delimiter longer than any backtick run
+fn code_span(text: &str) -> String {
+  let longest_run = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+  let delimiter = "`".repeat(longest_run + 1);
+  if longest_run > 0 {
+    format!("{delimiter} {text} {delimiter}")
+  } else {
+    format!("{delimiter}{text}{delimiter}")
+  }
+}
@@ -0,0 +1,156 @@
+use test_utils::*;
+
+assert_markdown!(
+  listing_fences,
+  adoc! {r#"
+    ----
+    has ``` backticks & <stuff>
+    ----
+
+    [source]
+    ....
+    literal
+    ....
+
+    :source-language: ruby
+
+    [source]
+    ----
+    puts 1
+    ----
+  "#},
+  adoc! {r#"
+    ````
+    has ``` backticks & <stuff>
+    ````
+
+    ```
+    literal
+    ```
+
+    ```ruby
+    puts 1
+    ```
+  "#}
+);
+
+assert_markdown!(
+  quotes_and_verses,
+  adoc! {r#"
+    [quote,Somebody,Somewhere]
+    ____
+    quoted
+    text
+
+    second para
+    ____
+
+    [verse,Poet]
+    ____
+    roses
+    are red
+    ____
+  "#},
+  adoc! {r#"
+    > quoted text
+    >
+    > second para
+    >
+    > — Somebody, Somewhere
+
+    > roses\
+    > are red
+    >
+    > — Poet
+  "#}
+);
+
+assert_markdown!(
+  admonition_w_title,
+  adoc! {r#"
+    .Heads up
+    WARNING: watch _out_
+
+    [NOTE]
+    ====
+    compound stem:[x]
+    ====
+  "#},
+  adoc! {r#"
+    > **Heads up**
+    >
+    > **WARNING:** watch _out_
+
+    > **NOTE:**
+    >
+    > compound $x$
+  "#}
+);
+
+assert_markdown!(
+  unsupported_blocks_degrade_to_content,
+  adoc! {r#"
+    .Sidebar
+    ****
+    side *content*
+    ****
+
+    ====
+    example
+    ====
+
+    [discrete]
+    === Discrete
+
+    '''
+
+    image::cat.png[A cat]
+  "#},
+  adoc! {r#"
+    **Sidebar**
+
+    side **content**
+
+    example
+
+    ### Discrete
+
+    ---
+
+    ![A cat](cat.png)
+  "#}
+);
+
+assert_markdown!(
+  pipe_tables,
+  adoc! {r#"
+    [cols="^1,1"]
+    |===
+    | a | *b*
+    a|
+    * x
+    * y
+    m| mono & <x>
+    |===
+  "#},
+  adoc! {r#"
+    |  |  |
+    | :---: | --- |
+    | a | **b** |
+    | - x<br>- y | `mono & <x>` |
+  "#}
+);
+
+assert_markdown!(
+  literal_cells_w_backticks,
+  adoc! {r#"
+    |===
+    l| run `ls` l| *plain*
+    |===
+  "#},
+  adoc! {r#"
+    |  |  |
+    | --- | --- |
+    | `` run `ls` `` | `*plain*` |
+  "#}
+);
@@ -0,0 +1,98 @@
+use asciidork_meta::JobSettings;
+use test_utils::*;
+
+assert_markdown!(
+  representative_document,
+  |settings: &mut JobSettings| settings.embedded = false,
+  adoc! {r#"
+    = Doc Title
+    :toc:
+
+    Preamble with *bold*, _italic_ and `mono` text.footnote:[A note.]
+
+    == Section 1
+
+    .Example
+    [source,rust]
+    ----
+    fn main() {} // <1>
+    ----
+    <1> the entry point
+
+    NOTE: Admonish *this*.
+
+    [%header,cols="1,>1"]
+    |===
+    | Name | Count
+    | a \| b | 2
+    |===
+
+    --
+    open content
+    --
+
+    === Sub
+
+    see <<_section_1>> and https://example.com[a link].
+  "#},
+  adoc! {r#"
+    # Doc Title
+
+    Preamble with **bold**, _italic_ and `mono` text.[^1]
+
+    ## Section 1
+
+    **Example**
+
+    ```rust
+    fn main() {} // <1>
+    ```
+
+    1. the entry point
+
+    > **NOTE:** Admonish **this**.
+
+    | Name | Count |
+    | --- | ---: |
+    | a \| b | 2 |
+
+    open content
+
+    ### Sub
+
+    see [Section 1](#_section_1) and [a link](https://example.com).
+
+    [^1]: A note.
+  "#}
+);
+
+assert_markdown!(
+  embedded_omits_doc_title,
+  adoc! {"
+    = Doc Title
+
+    foo
+  "},
+  "foo\n"
+);
+
+assert_markdown!(
+  inline_doctype,
+  |settings: &mut JobSettings| settings.doctype = Some(asciidork_meta::DocType::Inline),
+  "*bold* text",
+  "**bold** text"
+);
+
+assert_markdown!(
+  attr_decls_between_paragraphs,
+  adoc! {r#"
+    para one
+
+    :foo: bar
+    :baz: qux
+    :!nope:
+
+    para two {foo}
+  "#},
+  "para one\n\npara two bar\n"
+);
@@ -0,0 +1,65 @@
+use test_utils::*;
+
+assert_markdown!(
+  escapes_markdown_syntax,
+  "1 < 2 & a_b *not bold `+x_y+`",
+  "1 &lt; 2 &amp; a\\_b \\*not bold `x_y`\n"
+);
+
+assert_markdown!(
+  inline_formatting,
+  adoc! {r#"
+    :experimental:
+
+    H~2~O x^2^ #mark# "`quoted`" kbd:[Ctrl+C] btn:[OK] image:dot.png[]
+
+    line +
+    break
+  "#},
+  adoc! {r#"
+    H<sub>2</sub>O x<sup>2</sup> mark “quoted” <kbd>Ctrl</kbd>+<kbd>C</kbd> **OK** ![dot](dot.png)
+
+    line\
+    break
+  "#}
+);
+
+assert_markdown!(
+  interdocument_xrefs,
+  "See <<other.adoc#id,Other>> and xref:guide.adoc[].",
+  "See [Other](other.md#id) and [guide.md](guide.md).\n"
+);
+
+assert_markdown!(
+  escapes_passthrough_text,
+  adoc! {r#"
+    +*not bold* a_b+ and pass:[_x_] and `+*code*+`
+
+    ++++
+    *raw* [link]
+    ++++
+  "#},
+  adoc! {r#"
+    \*not bold\* a\_b and \_x\_ and `*code*`
+
+    \*raw\* \[link\]
+  "#}
+);
+
+assert_markdown!(
+  image_targets_w_spaces,
+  adoc! {r#"
+    :imagesdir: my images
+
+    image:dot.png[] image:/abs/a (1).png[A]
+  "#},
+  "![dot](<my images/dot.png>) ![A](</abs/a (1).png>)\n"
+);
+
+assert_markdown!(
+  image_alt_w_markup_uses_plain_text,
+  adoc! {r#"
+    image:tiger.png[a _big_ cat]
+  "#},
+  "![a big cat](tiger.png)\n"
+);
@@ -0,0 +1,31 @@
+use test_utils::*;
+
+assert_markdown!(
+  nested_lists,
+  adoc! {r#"
+    * one
+    ** nested
+    * [x] two
+    +
+    continued
+
+    [start=3]
+    . third
+    . fourth
+
+    term:: definition
+  "#},
+  adoc! {r#"
+    - one
+      - nested
+    - [x] two
+
+      continued
+
+    3. third
+    4. fourth
+       - **term**
+
+         definition
+  "#}
+);
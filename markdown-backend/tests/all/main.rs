@@ -0,0 +1,6 @@
+mod helpers;
+
+mod eval_blocks;
+mod eval_document;
+mod eval_inlines;
+mod eval_lists;
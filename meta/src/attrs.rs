@@ -135,6 +135,7 @@ impl Attrs {
         ("appendix-refsig", "Appendix"),
         ("caution-caption", "Caution"),
         ("chapter-refsig", "Chapter"),
+        ("equation-caption", "Equation"),
         ("example-caption", "Example"),
         ("figure-caption", "Figure"),
         ("important-caption", "Important"),
@@ -213,6 +214,18 @@ impl From<bool> for AttrValue {
   }
 }
 
+impl From<isize> for AttrValue {
+  fn from(n: isize) -> Self {
+    AttrValue::String(n.to_string())
+  }
+}
+
+impl From<i32> for AttrValue {
+  fn from(n: i32) -> Self {
+    AttrValue::String(n.to_string())
+  }
+}
+
 impl From<String> for AttrValue {
   fn from(s: String) -> Self {
     AttrValue::String(s)
@@ -65,6 +65,10 @@ impl DocumentMeta {
     }
   }
 
+  pub fn builder() -> DocumentMetaBuilder {
+    DocumentMetaBuilder::default()
+  }
+
   pub fn clone_for_cell(&self) -> Self {
     let mut dm = self.clone();
     dm.set_doctype(DocType::Article);
@@ -76,6 +80,12 @@ impl DocumentMeta {
     dm
   }
 
+  /// asciidoc table cells are evaluated as separate documents, so
+  /// they need the body attrs in effect where the table was declared
+  pub fn inherit_doc_attrs(&mut self, parent: &DocumentMeta) {
+    self.doc_attrs = parent.doc_attrs.clone();
+  }
+
   pub fn authors(&self) -> &[Author] {
     &self.authors
   }
@@ -84,13 +94,23 @@ impl DocumentMeta {
     _ = self.header_attrs.insert(key, AttrValue::String(value));
   }
 
+  /// overrides the email of the first author
+  pub fn set_author_email(&mut self, email: String) {
+    if let Some(author) = self.authors.first_mut() {
+      author.email = Some(email.clone());
+      self.insert_string_attr("email_1", email);
+    }
+  }
+
   pub fn add_author(&mut self, author: Author) {
     if self.authors.is_empty() {
       self.insert_string_attr("author", author.fullname());
       if let Some(email) = author.email.as_ref() {
         self.insert_string_attr("email", email.clone());
       }
-      self.insert_string_attr("lastname", author.last_name.clone());
+      if !author.last_name.is_empty() {
+        self.insert_string_attr("lastname", author.last_name.clone());
+      }
       if let Some(middle_name) = author.middle_name.as_ref() {
         self.insert_string_attr("middlename", middle_name.clone());
       }
@@ -103,7 +123,9 @@ impl DocumentMeta {
     if let Some(email) = author.email.as_ref() {
       self.insert_string_attr(&format!("email_{}", n), email.clone());
     }
-    self.insert_string_attr(&format!("lastname_{}", n), author.last_name.clone());
+    if !author.last_name.is_empty() {
+      self.insert_string_attr(&format!("lastname_{}", n), author.last_name.clone());
+    }
     if let Some(middle_name) = author.middle_name.as_ref() {
       self.insert_string_attr(&format!("middlename_{}", n), middle_name.clone());
     }
@@ -232,6 +254,59 @@ impl ReadAttr for DocumentMeta {
   }
 }
 
+/// seeds job-level attributes programmatically, for embedders that
+/// need a `DocumentMeta` without going through the CLI, the result
+/// is handed to the parser via `Parser::apply_document_meta`
+#[derive(Debug, Default)]
+pub struct DocumentMetaBuilder {
+  settings: JobSettings,
+  error: Option<String>,
+}
+
+impl DocumentMetaBuilder {
+  pub const fn safe_mode(mut self, safe_mode: SafeMode) -> Self {
+    self.settings.safe_mode = safe_mode;
+    self
+  }
+
+  pub const fn doctype(mut self, doctype: DocType) -> Self {
+    self.settings.doctype = Some(doctype);
+    self
+  }
+
+  pub const fn embedded(mut self, embedded: bool) -> Self {
+    self.settings.embedded = embedded;
+    self
+  }
+
+  /// sets an attribute the document is allowed to override
+  pub fn attr(self, key: &str, value: impl Into<AttrValue>) -> Self {
+    self.insert(key, JobAttr::modifiable(value))
+  }
+
+  /// sets an attribute the document can not override
+  pub fn locked_attr(self, key: &str, value: impl Into<AttrValue>) -> Self {
+    self.insert(key, JobAttr::readonly(value))
+  }
+
+  /// returns the first invalid attribute error, if any
+  pub fn build(self) -> Result<DocumentMeta, String> {
+    match self.error {
+      Some(err) => Err(err),
+      None => Ok(self.settings.into()),
+    }
+  }
+
+  fn insert(mut self, key: &str, job_attr: JobAttr) -> Self {
+    if self.error.is_none() {
+      if let Err(err) = self.settings.job_attrs.insert(key, job_attr) {
+        self.error = Some(err);
+      }
+    }
+    self
+  }
+}
+
 lazy_static::lazy_static! {
   static ref JOB_ONLY: HashSet<&'static str> = {
     HashSet::from_iter(vec![
@@ -316,6 +391,42 @@ mod tests {
     assert_eq!(attrs.str("safe-mode-name"), Some("SECURE"));
   }
 
+  #[test]
+  fn builder() {
+    let mut meta = DocumentMeta::builder()
+      .safe_mode(SafeMode::Safe)
+      .doctype(DocType::Book)
+      .embedded(true)
+      .attr("icons", "font")
+      .attr("sectnums", true)
+      .attr("sectnumlevels", 2)
+      .attr("leveloffset", -1isize)
+      .locked_attr("experimental", true)
+      .build()
+      .unwrap();
+
+    assert!(meta.embedded);
+    assert_eq!(meta.get_doctype(), DocType::Book);
+    assert_eq!(meta.str("safe-mode-name"), Some("SAFE"));
+    assert_eq!(meta.str("icons"), Some("font"));
+    assert!(meta.is_true("sectnums"));
+    assert_eq!(meta.u8("sectnumlevels"), Some(2));
+    assert_eq!(meta.isize("leveloffset"), Some(-1));
+
+    // modifiable attrs yield to the document, locked ones do not
+    meta.insert_header_attr("icons", "image").unwrap();
+    meta.insert_header_attr("experimental", false).unwrap();
+    assert_eq!(meta.str("icons"), Some("image"));
+    assert!(meta.is_true("experimental"));
+
+    let err = DocumentMeta::builder()
+      .attr("attribute-missing", "explode")
+      .attr("icons", "font")
+      .build()
+      .unwrap_err();
+    assert!(err.contains("attribute-missing"));
+  }
+
   #[test]
   fn defaults() {
     let mut attrs = DocumentMeta::default();
@@ -340,6 +451,21 @@ mod tests {
     assert_eq!(attrs.str("safe-mode-name"), Some("SERVER"));
   }
 
+  #[test]
+  fn author_from_fullname() {
+    let author = Author::from_fullname("John  Q. Public").unwrap();
+    assert_eq!(author.first_name, "John");
+    assert_eq!(author.middle_name.as_deref(), Some("Q."));
+    assert_eq!(author.last_name, "Public");
+    assert_eq!(author.fullname(), "John Q. Public");
+    assert_eq!(author.initials(), "JQP");
+
+    let author = Author::from_fullname("Madonna").unwrap();
+    assert_eq!(author.fullname(), "Madonna");
+    assert_eq!(author.initials(), "M");
+    assert!(Author::from_fullname(" ").is_none());
+  }
+
   #[test]
   fn doctype() {
     let mut attrs = DocumentMeta::default();
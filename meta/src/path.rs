@@ -154,6 +154,60 @@ impl Path {
   pub fn is_uri(&self) -> bool {
     matches!(self.components.first(), Some(Component::UriScheme(_)))
   }
+
+  /// true for targets (like image targets) which are used verbatim rather
+  /// than resolved against a base directory such as `imagesdir`: anything
+  /// w/ a uri scheme (`https:`, `ftp:`, `data:`...), rooted paths, and
+  /// windows paths starting with a drive letter
+  pub fn is_absolute_or_uri(target: &str) -> bool {
+    Path::has_uri_scheme(target)
+      || drive_prefix(target).is_some()
+      || Path::new(target).is_absolute()
+  }
+
+  /// true for remote or inline targets, i.e. not a local file
+  pub fn has_uri_scheme(target: &str) -> bool {
+    let Some((scheme, _)) = target.split_once(':') else {
+      return false;
+    };
+    let mut chars = scheme.chars();
+    // NB: a scheme is 2+ chars, so it's not mistaken for a drive letter
+    scheme.len() > 1
+      && chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+      && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'))
+  }
+
+  /// resolves `.` and `..` components lexically, w/out touching the filesystem
+  pub fn normalize(&self) -> Path {
+    let mut components: Vec<Component> = Vec::with_capacity(self.components.len());
+    for component in &self.components {
+      match component {
+        Component::CurrentDir => {}
+        Component::ParentDir => match components.last() {
+          Some(Component::Normal(_)) => {
+            components.pop();
+          }
+          // NB: `..` at the root stays at the root
+          Some(Component::Root | Component::UriScheme(_)) => {}
+          _ => components.push(Component::ParentDir),
+        },
+        _ => components.push(component.clone()),
+      }
+    }
+    Path {
+      separator: self.separator,
+      components,
+    }
+  }
+
+  /// true if the path, once normalized, does not escape `jail`
+  pub fn is_within(&self, jail: &Path) -> bool {
+    let path = self.normalize();
+    let jail = jail.normalize();
+    !path.components.contains(&Component::ParentDir)
+      && (jail.is_absolute() || path.is_relative())
+      && path.components.starts_with(&jail.components)
+  }
 }
 
 impl From<std::path::PathBuf> for Path {
@@ -273,6 +327,21 @@ mod tests {
     assert!(!path(r#"c:foo"#).is_absolute());
   }
 
+  #[test]
+  fn path_is_absolute_or_uri() {
+    assert!(Path::is_absolute_or_uri("/images/a.png"));
+    assert!(Path::is_absolute_or_uri("https://example.com/a.png"));
+    assert!(Path::is_absolute_or_uri("ftp://example.com/a.png"));
+    assert!(Path::is_absolute_or_uri("ftp:a.png"));
+    assert!(Path::is_absolute_or_uri(r#"C:\images\a.png"#));
+    assert!(Path::is_absolute_or_uri("c:/images/a.png"));
+    assert!(Path::is_absolute_or_uri(r#"\images\a.png"#));
+    assert!(Path::is_absolute_or_uri("data:image/gif;base64,R0lGODlh"));
+    assert!(!Path::is_absolute_or_uri("images/a.png"));
+    assert!(!Path::is_absolute_or_uri("a.png"));
+    assert!(!Path::is_absolute_or_uri("images/a:b.png"));
+  }
+
   #[test]
   fn path_push_pop() {
     let mut path = Path::new("/usr/local");
@@ -347,6 +416,27 @@ mod tests {
     assert_eq!("foo.tar", path("foo.tar.gz").file_stem());
   }
 
+  #[test]
+  fn path_normalize() {
+    assert_eq!(path("/a/./b/../c").normalize().to_string(), "/a/c");
+    assert_eq!(path("/../etc").normalize().to_string(), "/etc");
+    assert_eq!(path("a/../../b").normalize().to_string(), "../b");
+  }
+
+  #[test]
+  fn path_is_within() {
+    let jail = path("/docs");
+    assert!(path("/docs/images/a.png").is_within(&jail));
+    assert!(path("/docs/x/../a.png").is_within(&jail));
+    assert!(!path("/docs/../../etc/x").is_within(&jail));
+    assert!(!path("/tmp/secret.txt").is_within(&jail));
+    assert!(!path("/docsecret/a.png").is_within(&jail));
+    let relative = path("");
+    assert!(path("images/a.png").is_within(&relative));
+    assert!(!path("../a.png").is_within(&relative));
+    assert!(!path("/tmp/a.png").is_within(&relative));
+  }
+
   #[test]
   fn join_uri_relative() {
     let src = Path::new("https://example.com/foo/bar");
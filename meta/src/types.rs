@@ -39,6 +39,25 @@ pub struct Author {
 }
 
 impl Author {
+  /// derives the name parts from a full name, as when
+  /// an author is set by the `author` attribute
+  pub fn from_fullname(name: &str) -> Option<Self> {
+    let mut parts = name.split_whitespace().collect::<Vec<_>>();
+    let first_name = parts.first()?.to_string();
+    let last_name = if parts.len() > 1 {
+      parts.pop().unwrap().to_string()
+    } else {
+      String::new()
+    };
+    let middle_name = if parts.len() > 1 { Some(parts[1..].join(" ")) } else { None };
+    Some(Author {
+      first_name,
+      middle_name,
+      last_name,
+      email: None,
+    })
+  }
+
   pub fn fullname(&self) -> String {
     let mut name = String::with_capacity(64);
     name.push_str(&self.first_name);
@@ -46,18 +65,20 @@ impl Author {
       name.push(' ');
       name.push_str(middle_name);
     }
-    name.push(' ');
-    name.push_str(&self.last_name);
+    if !self.last_name.is_empty() {
+      name.push(' ');
+      name.push_str(&self.last_name);
+    }
     name
   }
 
   pub fn initials(&self) -> String {
     let mut initials = String::with_capacity(3);
-    initials.push(self.first_name.chars().next().unwrap());
+    initials.extend(self.first_name.chars().next());
     if let Some(middle_name) = &self.middle_name {
-      initials.push(middle_name.chars().next().unwrap());
+      initials.extend(middle_name.chars().next());
     }
-    initials.push(self.last_name.chars().next().unwrap());
+    initials.extend(self.last_name.chars().next());
     initials
   }
 }
@@ -23,6 +23,18 @@ impl<'arena> Parser<'arena> {
     })
   }
 
+  pub(crate) fn warn_at(&self, message: impl Into<String>, start: u32, end: u32) {
+    let (line_num, offset) = self.lexer.line_number_with_offset(start);
+    self.warnings.borrow_mut().push(Diagnostic {
+      line_num,
+      line: self.lexer.line_of(start).to_string(),
+      message: message.into(),
+      underline_start: offset,
+      underline_width: end - start,
+      source_file: self.lexer.source_file().clone(),
+    });
+  }
+
   pub(crate) fn err_line(&self, message: impl Into<String>, line: &Line) -> Result<()> {
     let start = line.loc().expect("non empty line for `err_line`").start;
     let (line_num, offset) = self.lexer.line_number_with_offset(start);
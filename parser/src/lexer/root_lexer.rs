@@ -56,6 +56,7 @@ impl<'arena> RootLexer<'arena> {
     src_file: SourceFile,
     leveloffset: i8,
     max_include_depth: Option<u16>,
+    selection: Option<BumpString<'arena>>,
     mut src_bytes: BumpVec<'arena, u8>,
   ) {
     // match asciidoctor - its include processor returns an array of lines
@@ -64,13 +65,15 @@ impl<'arena> RootLexer<'arena> {
     if src_bytes.last() != Some(&b'\n') {
       src_bytes.push(b'\n');
     }
-    self.sources.push(SourceLexer::new(
+    let mut source = SourceLexer::new(
       src_bytes,
       src_file,
       leveloffset,
       max_include_depth,
       self.bump,
-    ));
+    );
+    source.selection = selection;
+    self.sources.push(source);
     let next_idx = self.sources.len() as u16 - 1;
     self.next_idx = Some(next_idx);
   }
@@ -221,6 +224,15 @@ impl<'arena> RootLexer<'arena> {
     self.idx
   }
 
+  /// true if `file` with the same `lines`/`tag(s)` selection is the current
+  /// source, or one of the sources (transitively) including the current source
+  pub fn is_including(&self, file: &SourceFile, selection: Option<&str>) -> bool {
+    std::iter::once(&self.idx)
+      .chain(self.source_stack.iter())
+      .map(|idx| &self.sources[*idx as usize])
+      .any(|src| src.file == *file && src.selection.as_deref() == selection)
+  }
+
   pub fn max_include_depth(&self) -> Option<(u16, u16)> {
     self
       .sources
@@ -547,6 +559,16 @@ mod tests {
       ("http://", vec![(UriScheme, "http://")]),
       ("https://", vec![(UriScheme, "https://")]),
       ("file:///", vec![(UriScheme, "file:///")]),
+      ("mailto:a", vec![(UriScheme, "mailto:"), (Word, "a")]),
+      (
+        "mailto: a",
+        vec![
+          (Word, "mailto"),
+          (Colon, ":"),
+          (Whitespace, " "),
+          (Word, "a")
+        ]
+      ),
       (
         "(http://foo)",
         vec![
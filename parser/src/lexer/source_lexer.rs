@@ -11,6 +11,8 @@ pub struct SourceLexer<'arena> {
   pub file: SourceFile,
   pub leveloffset: i8,
   pub max_include_depth: Option<u16>,
+  // the `lines`/`tag(s)` selection the source was included with, if any
+  pub selection: Option<BumpString<'arena>>,
 }
 
 impl<'arena> SourceLexer<'arena> {
@@ -29,6 +31,7 @@ impl<'arena> SourceLexer<'arena> {
       leveloffset,
       file,
       max_include_depth,
+      selection: None,
     }
   }
 
@@ -45,6 +48,7 @@ impl<'arena> SourceLexer<'arena> {
       leveloffset: 0,
       file,
       max_include_depth: None,
+      selection: None,
     }
   }
 
@@ -316,6 +320,7 @@ impl<'arena> SourceLexer<'arena> {
     matches!(
       lexeme,
       b"footnote"
+        | b"audio"
         | b"image"
         | b"anchor"
         | b"icon"
@@ -323,8 +328,12 @@ impl<'arena> SourceLexer<'arena> {
         | b"link"
         | b"pass"
         | b"btn"
+        | b"stem"
+        | b"latexmath"
+        | b"asciimath"
         | b"menu"
         | b"toc"
+        | b"video"
         | b"xref"
     )
   }
@@ -567,11 +576,8 @@ impl<'arena> SourceLexer<'arena> {
 
   fn continues_uri_scheme(&self, lexeme: &[u8]) -> Option<u32> {
     match lexeme {
-      b"http" | b"https" | b"ftp" | b"mailto" | b"irc"
-        if self.peek_bytes::<3>() == Some(b"://") =>
-      {
-        Some(3)
-      }
+      b"http" | b"https" | b"ftp" | b"irc" if self.peek_bytes::<3>() == Some(b"://") => Some(3),
+      b"mailto" if self.peek_n(1).is_some_and(|c| c.is_ascii_alphanumeric()) => Some(1),
       b"file" if self.peek_bytes::<4>() == Some(b":///") => Some(4),
       _ => None,
     }
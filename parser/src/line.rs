@@ -391,6 +391,32 @@ impl<'arena> Line<'arena> {
     SourceString::new(s, loc)
   }
 
+  /// like `consume_to_string_until`, but treats a backslash-escaped
+  /// `kind` token as content, dropping the backslash
+  #[must_use]
+  pub fn consume_to_string_until_nonescaped(
+    &mut self,
+    kind: TokenKind,
+    bump: &'arena Bump,
+  ) -> SourceString<'arena> {
+    let mut loc = self.loc().expect("no tokens to consume");
+    let mut s = BumpString::new_in(bump);
+    while !self.current_is(kind) {
+      let Some(token) = self.consume_current() else {
+        break;
+      };
+      loc.extend(token.loc);
+      if token.is(Backslash) && self.current_is(kind) {
+        let escaped = self.consume_current().unwrap();
+        s.push_str(&escaped.lexeme);
+        loc.extend(escaped.loc);
+      } else if token.kind != AttrRef {
+        s.push_str(&token.lexeme);
+      }
+    }
+    SourceString::new(s, loc)
+  }
+
   #[must_use]
   pub fn consume_to_string_until_one_of(
     &mut self,
@@ -14,8 +14,17 @@ pub struct ParseContext<'arena> {
   pub custom_line_comment: Option<SmallVec<[u8; 3]>>,
   pub anchor_ids: Rc<RefCell<HashSet<BumpString<'arena>>>>,
   pub xrefs: Rc<RefCell<HashMap<BumpString<'arena>, SourceLocation>>>,
+  /// explicit ids, in declaration order, for duplicate detection
+  pub declared_ids: Rc<RefCell<Vec<(BumpString<'arena>, SourceLocation)>>>,
   pub num_footnotes: Rc<RefCell<u16>>,
+  pub num_figures: Rc<RefCell<usize>>,
+  pub num_tables: Rc<RefCell<usize>>,
+  pub num_examples: Rc<RefCell<usize>>,
+  pub num_equations: Rc<RefCell<usize>>,
+  pub dependencies: Rc<RefCell<Vec<Dependency>>>,
   pub saw_toc_macro: bool,
+  /// inside a block with the `hidden` role, which the backend won't render
+  pub in_hidden_block: bool,
   pub table_cell_ctx: TableCellContext,
   pub passthrus: BumpVec<'arena, Option<InlineNodes<'arena>>>,
   pub max_include_depth: u16,
@@ -43,8 +52,15 @@ impl<'arena> ParseContext<'arena> {
       custom_line_comment: None,
       anchor_ids: Rc::new(RefCell::new(HashSet::new())),
       xrefs: Rc::new(RefCell::new(HashMap::new())),
+      declared_ids: Rc::new(RefCell::new(Vec::new())),
       num_footnotes: Rc::new(RefCell::new(0)),
+      num_figures: Rc::new(RefCell::new(0)),
+      num_tables: Rc::new(RefCell::new(0)),
+      num_examples: Rc::new(RefCell::new(0)),
+      num_equations: Rc::new(RefCell::new(0)),
+      dependencies: Rc::new(RefCell::new(Vec::new())),
       saw_toc_macro: false,
+      in_hidden_block: false,
       table_cell_ctx: TableCellContext::None,
       passthrus: BumpVec::new_in(bump),
       max_include_depth: 64,
@@ -64,8 +80,15 @@ impl<'arena> ParseContext<'arena> {
       custom_line_comment: None,
       anchor_ids: Rc::clone(&self.anchor_ids),
       xrefs: Rc::clone(&self.xrefs),
+      declared_ids: Rc::clone(&self.declared_ids),
       num_footnotes: Rc::clone(&self.num_footnotes),
+      num_figures: Rc::clone(&self.num_figures),
+      num_tables: Rc::clone(&self.num_tables),
+      num_examples: Rc::clone(&self.num_examples),
+      num_equations: Rc::clone(&self.num_equations),
+      dependencies: Rc::clone(&self.dependencies),
       saw_toc_macro: false,
+      in_hidden_block: self.in_hidden_block,
       table_cell_ctx: TableCellContext::AsciiDocCell,
       passthrus: BumpVec::new_in(bump),
       max_include_depth: 64,
@@ -98,13 +121,17 @@ impl<'arena> ParseContext<'arena> {
     *callouts.last().unwrap()
   }
 
-  pub fn advance_callout_list(&mut self, bump: &'arena Bump) {
+  /// returns `false` if there is no room for another callout list
+  pub fn advance_callout_list(&mut self, bump: &'arena Bump) -> bool {
     let last = { self.callouts.borrow().last().copied() };
-    if let Some(last) = last {
-      self.callouts = Rc::new(RefCell::new(
-        bvec![in bump; Callout::new(last.list_idx + 1, 0, 0 )],
-      ));
-    }
+    let Some(last) = last else {
+      return true;
+    };
+    let Some(list_idx) = last.list_idx.checked_add(1) else {
+      return false;
+    };
+    self.callouts = Rc::new(RefCell::new(bvec![in bump; Callout::new(list_idx, 0, 0)]));
+    true
   }
 
   pub fn get_callouts(&self, number: u8) -> SmallVec<[Callout; 4]> {
@@ -123,6 +150,9 @@ impl<'arena> ParseContext<'arena> {
       BlockContext::Passthrough => {
         self.subs = Substitutions::none();
       }
+      BlockContext::Stem => {
+        self.subs = Substitutions::only_special_chars();
+      }
       BlockContext::Listing | BlockContext::Literal => {
         self.subs = Substitutions::verbatim();
       }
@@ -137,3 +167,21 @@ impl<'arena> ParseContext<'arena> {
     restore
   }
 }
+
+#[cfg(test)]
+mod tests {
+  use super::*;
+  use test_utils::*;
+
+  #[test]
+  fn advance_callout_list_overflow() {
+    let bump = leaked_bump();
+    let mut ctx = ParseContext::new(bump);
+    assert!(ctx.advance_callout_list(bump));
+    ctx.push_callout(None);
+    assert!(ctx.advance_callout_list(bump));
+    expect_eq!(ctx.push_callout(None), Callout::new(1, 0, 1));
+    ctx.callouts = Rc::new(RefCell::new(bvec![in bump; Callout::new(u16::MAX, 0, 1)]));
+    assert!(!ctx.advance_callout_list(bump));
+  }
+}
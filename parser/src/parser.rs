@@ -1,4 +1,4 @@
-use std::{cell::RefCell, rc::Rc};
+use std::{cell::RefCell, collections::HashMap, path::PathBuf, rc::Rc};
 
 use crate::internal::*;
 
@@ -10,6 +10,8 @@ pub struct Parser<'arena> {
   pub(super) peeked_meta: Option<ChunkMeta<'arena>>,
   pub(super) ctx: ParseContext<'arena>,
   pub(super) errors: RefCell<Vec<Diagnostic>>,
+  /// non-fatal diagnostics, reported even in strict mode
+  pub(super) warnings: RefCell<Vec<Diagnostic>>,
   pub(super) strict: bool, // todo: naming...
   pub(super) include_resolver: Option<Box<dyn IncludeResolver>>,
 }
@@ -17,6 +19,41 @@ pub struct Parser<'arena> {
 pub struct ParseResult<'arena> {
   pub document: Document<'arena>,
   pub warnings: Vec<Diagnostic>,
+  pub(crate) dependencies: Vec<Dependency>,
+}
+
+impl<'arena> ParseResult<'arena> {
+  /// local files the document depends on (includes, images and
+  /// stylesheets) in the order first encountered, e.g. for build systems
+  pub fn dependencies(&self) -> Vec<PathBuf> {
+    self.dependencies.iter().map(Dependency::path_buf).collect()
+  }
+
+  /// the images and stylesheets among the document's dependencies
+  pub fn assets(&self) -> Vec<PathBuf> {
+    self
+      .dependencies
+      .iter()
+      .filter(|dependency| matches!(dependency, Dependency::Asset(_)))
+      .map(Dependency::path_buf)
+      .collect()
+  }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum Dependency {
+  /// a resolved `include::[]` target
+  Include(Path),
+  /// an image or stylesheet, resolved against `imagesdir` or `stylesdir`
+  Asset(Path),
+}
+
+impl Dependency {
+  fn path_buf(&self) -> PathBuf {
+    match self {
+      Dependency::Include(path) | Dependency::Asset(path) => PathBuf::from(path.to_string()),
+    }
+  }
 }
 
 #[derive(Debug, Default)]
@@ -42,6 +79,7 @@ impl<'arena> Parser<'arena> {
       peeked_meta: None,
       ctx: ParseContext::new(lexer.bump),
       errors: RefCell::new(Vec::new()),
+      warnings: RefCell::new(Vec::new()),
       strict: true,
       include_resolver: None,
       lexer,
@@ -51,12 +89,17 @@ impl<'arena> Parser<'arena> {
   }
 
   pub fn apply_job_settings(&mut self, settings: JobSettings) {
-    if let Some(leveloffset) = settings.job_attrs.get("leveloffset") {
-      Parser::adjust_leveloffset(&mut self.ctx.leveloffset, &leveloffset.value);
-    }
     self.strict = settings.strict;
-    self.ctx.max_include_depth = settings.job_attrs.u16("max-include-depth").unwrap_or(64);
-    self.document.meta = settings.into();
+    self.apply_document_meta(settings.into());
+  }
+
+  /// seeds the document with pre-built meta, e.g. from `DocumentMeta::builder()`
+  pub fn apply_document_meta(&mut self, meta: DocumentMeta) {
+    if let Some(leveloffset) = meta.get("leveloffset") {
+      Parser::adjust_leveloffset(&mut self.ctx.leveloffset, leveloffset);
+    }
+    self.ctx.max_include_depth = meta.u16("max-include-depth").unwrap_or(64);
+    self.document.meta = meta;
     self.set_source_file_attrs();
   }
 
@@ -71,6 +114,7 @@ impl<'arena> Parser<'arena> {
     cell_parser.ctx = self.ctx.clone_for_cell(self.bump);
     cell_parser.document.meta = self.document.meta.clone_for_cell();
     cell_parser.document.anchors = Rc::clone(&self.document.anchors);
+    cell_parser.document.footnotes = Rc::clone(&self.document.footnotes);
     cell_parser
   }
 
@@ -196,6 +240,7 @@ impl<'arena> Parser<'arena> {
 
   pub fn parse(mut self) -> std::result::Result<ParseResult<'arena>, Vec<Diagnostic>> {
     self.parse_document_header()?;
+    self.push_stylesheet_dependency();
 
     // ensure we only read a single "paragraph" for `inline` doc_type
     // https://docs.asciidoctor.org/asciidoc/latest/document/doctype/#inline-doctype-rules
@@ -221,7 +266,8 @@ impl<'arena> Parser<'arena> {
 
     Ok(ParseResult {
       document: self.document,
-      warnings: vec![],
+      warnings: self.warnings.take(),
+      dependencies: self.ctx.dependencies.take(),
     })
   }
 
@@ -253,7 +299,8 @@ impl<'arena> Parser<'arena> {
         Some(line) if line.is_block_attr_list() => {
           let mut line = lines.consume_current().unwrap();
           line.discard_assert(TokenKind::OpenBracket);
-          attrs = Some(self.parse_block_attr_list(&mut line)?);
+          let line_attrs = self.parse_block_attr_list(&mut line)?;
+          attrs = Some(merge_attrs(attrs, line_attrs));
         }
         Some(line) if line.is_block_anchor() => {
           let mut line = lines.consume_current().unwrap();
@@ -262,13 +309,24 @@ impl<'arena> Parser<'arena> {
           let anchor = self.parse_block_anchor(&mut line)?.unwrap();
           let mut anchor_attrs = AttrList::new(anchor.loc, self.bump);
           anchor_attrs.id = Some(anchor.id);
-          anchor_attrs.positional.push(anchor.reftext);
-          attrs = Some(anchor_attrs);
+          if let Some(reftext) = anchor.reftext {
+            let key = SourceString::new(self.string("reftext"), anchor.loc);
+            anchor_attrs.insert_named(key, reftext);
+          }
+          attrs = Some(merge_attrs(attrs, anchor_attrs));
         }
         _ => break,
       }
     }
-    Ok(ChunkMeta { attrs, title, start })
+    Ok(ChunkMeta::new(attrs, title, start))
+  }
+
+  pub(crate) fn declare_id(&self, id: &SourceString<'arena>) {
+    self
+      .ctx
+      .declared_ids
+      .borrow_mut()
+      .push((id.src.clone(), id.loc));
   }
 
   fn diagnose_document(&self) -> Result<()> {
@@ -281,6 +339,19 @@ impl<'arena> Parser<'arena> {
           )?;
         }
       }
+      let mut first_locs: HashMap<&str, SourceLocation> = HashMap::new();
+      for (id, loc) in self.ctx.declared_ids.borrow().iter() {
+        if let Some(first_loc) = first_locs.get(id.as_str()) {
+          let (first_line, _) = self.lexer.line_number_with_offset(first_loc.start);
+          self.warn_at(
+            format!("Duplicate id `{id}`, first declared on line {first_line}"),
+            loc.start,
+            loc.end,
+          );
+        } else {
+          first_locs.insert(id.as_str(), *loc);
+        }
+      }
     }
     let toc_pos = self.document.toc.as_ref().map(|toc| toc.position);
     match toc_pos {
@@ -352,6 +423,17 @@ impl SourceFile {
   }
 }
 
+/// consecutive attr lines (and block anchors) apply to the same block
+fn merge_attrs<'arena>(prev: Option<AttrList<'arena>>, next: AttrList<'arena>) -> AttrList<'arena> {
+  match prev {
+    Some(mut prev) => {
+      prev.merge(next);
+      prev
+    }
+    None => next,
+  }
+}
+
 impl From<Diagnostic> for Vec<Diagnostic> {
   fn from(diagnostic: Diagnostic) -> Self {
     vec![diagnostic]
@@ -515,6 +597,37 @@ mod tests {
     );
   }
 
+  #[test]
+  fn apply_document_meta_from_builder() {
+    let meta = DocumentMeta::builder()
+      .doctype(DocType::Book)
+      .attr("foo", "bar")
+      .attr("leveloffset", 1isize)
+      .locked_attr("icons", "font")
+      .build()
+      .unwrap();
+    let input = "{foo}";
+    let mut parser = test_parser!(input);
+    parser.apply_document_meta(meta.clone());
+    expect_eq!(parser.ctx.leveloffset, 1, from: input);
+    expect_eq!(
+      parser.read_line().unwrap().unwrap().reassemble_src(),
+      "bar",
+      from: input
+    );
+
+    let input = adoc! {"
+      :icons: image
+      :foo: baz
+    "};
+    let mut parser = test_parser!(input);
+    parser.apply_document_meta(meta);
+    let document = parser.parse().unwrap().document;
+    expect_eq!(document.meta.get_doctype(), DocType::Book, from: input);
+    expect_eq!(document.meta.str("icons"), Some("font"), from: input);
+    expect_eq!(document.meta.str("foo"), Some("baz"), from: input);
+  }
+
   #[test]
   fn uri_read_not_allowed_include_non_strict() {
     // non-strict mode replaced with link
@@ -128,6 +128,12 @@ impl Substitutions {
     self.flags & Subs::CALLOUTS != 0
   }
 
+  /// backslash escapes are only consumed when a substitution
+  /// they could suppress is enabled, i.e. not special chars alone
+  pub const fn consumes_escapes(&self) -> bool {
+    self.flags & !(Subs::SPECIAL_CHARS | Subs::CALLOUTS) != 0
+  }
+
   pub fn insert(&mut self, sub: Subs) {
     if self.contains(sub) {
       return;
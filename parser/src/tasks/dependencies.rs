@@ -0,0 +1,47 @@
+use crate::internal::*;
+
+impl<'arena> Parser<'arena> {
+  pub(crate) fn push_image_dependencies(&self, target: &str, attrs: &AttrList) {
+    self.push_image_dependency(target);
+    // NB: interactive svgs fall back to a second image
+    if let Some(fallback) = attrs.named("fallback") {
+      self.push_image_dependency(fallback);
+    }
+  }
+
+  fn push_image_dependency(&self, target: &str) {
+    let path = match self.document.meta.str("imagesdir") {
+      Some(imagesdir) if !Path::is_absolute_or_uri(target) => Path::new(imagesdir).join(target),
+      _ => Path::new(target),
+    };
+    if Path::has_uri_scheme(&path.to_string()) {
+      return;
+    }
+    self.push_dependency(Dependency::Asset(path));
+  }
+
+  pub(crate) fn push_stylesheet_dependency(&self) {
+    let meta = &self.document.meta;
+    // NB: w/out a `stylesheet` the backend uses its builtin styles
+    let Some(stylesheet) = meta.str("stylesheet").filter(|s| !s.is_empty()) else {
+      return;
+    };
+    let path = match meta.str("stylesdir") {
+      Some(stylesdir) if !Path::is_absolute_or_uri(stylesheet) => {
+        Path::new(stylesdir).join(stylesheet)
+      }
+      _ => Path::new(stylesheet),
+    };
+    if Path::has_uri_scheme(&path.to_string()) {
+      return;
+    }
+    self.push_dependency(Dependency::Asset(path));
+  }
+
+  pub(crate) fn push_dependency(&self, dependency: Dependency) {
+    let mut dependencies = self.ctx.dependencies.borrow_mut();
+    if !dependencies.contains(&dependency) {
+      dependencies.push(dependency);
+    }
+  }
+}
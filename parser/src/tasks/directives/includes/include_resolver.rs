@@ -81,6 +81,7 @@ pub enum ResolveError {
   UriReadNotSupported,
   UriRead(String),
   BaseDirRequired,
+  OutsideBaseDir,
 }
 
 impl fmt::Display for ResolveError {
@@ -96,7 +97,52 @@ impl fmt::Display for ResolveError {
           "Include resolvers must supply a base_dir for relative includes from primary document"
         )
       }
+      ResolveError::OutsideBaseDir => {
+        write!(f, "Cannot include files outside of the base directory")
+      }
+    }
+  }
+}
+
+/// default resolver when none is supplied, reads files relative to
+/// `base_dir`, which only unsafe mode may escape (as in asciidoctor)
+#[derive(Debug, Clone)]
+pub struct FsResolver {
+  base_dir: Path,
+  safe_mode: SafeMode,
+}
+
+impl FsResolver {
+  pub fn new(base_dir: impl Into<Path>, safe_mode: SafeMode) -> Self {
+    Self { base_dir: base_dir.into(), safe_mode }
+  }
+}
+
+impl IncludeResolver for FsResolver {
+  fn resolve(
+    &mut self,
+    target: IncludeTarget,
+    buffer: &mut dyn IncludeBuffer,
+  ) -> std::result::Result<usize, ResolveError> {
+    let IncludeTarget::FilePath(path) = target else {
+      return Err(ResolveError::UriReadNotSupported);
+    };
+    let path = Path::new(path);
+    if self.safe_mode > SafeMode::Unsafe && !path.is_within(&self.base_dir) {
+      return Err(ResolveError::OutsideBaseDir);
     }
+    let bytes = match std::fs::read(path.to_string()) {
+      Ok(bytes) => bytes,
+      Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Err(ResolveError::NotFound),
+      Err(err) => return Err(err.into()),
+    };
+    buffer.initialize(bytes.len());
+    buffer.as_bytes_mut().copy_from_slice(&bytes);
+    Ok(bytes.len())
+  }
+
+  fn get_base_dir(&self) -> Option<String> {
+    Some(self.base_dir.to_string())
   }
 }
 
@@ -52,6 +52,11 @@ impl<'arena> Parser<'arena> {
       return Ok(DirectiveAction::Passthrough);
     }
 
+    let selection = self.include_selection(&directive.attrs);
+    #[cfg(not(target_arch = "wasm32"))]
+    if self.include_resolver.is_none() {
+      self.include_resolver = self.default_include_resolver();
+    }
     let Some(resolver) = self.include_resolver.as_mut() else {
       self.err_token_full(
         "No resolver supplied for include directive",
@@ -77,9 +82,25 @@ impl<'arena> Parser<'arena> {
     };
 
     let target_abspath = target.path();
+    let target_is_path = target.is_path();
+    if target_is_path
+      && self.lexer.is_including(
+        &SourceFile::Path(target_abspath.clone()),
+        selection.as_deref(),
+      )
+    {
+      self.target_err(
+        format!("Circular include of `{}` detected", target_abspath),
+        &directive,
+      )?;
+      return Ok(DirectiveAction::Passthrough);
+    }
     let mut buffer = BumpVec::new_in(self.bump);
     match resolver.resolve(target, &mut buffer) {
       Ok(_) => {
+        if target_is_path {
+          self.push_dependency(Dependency::Include(target_abspath.clone()));
+        }
         if let Err(msg) =
           self.normalize_include_bytes(&target_abspath, &directive.attrs, &mut buffer)
         {
@@ -106,6 +127,7 @@ impl<'arena> Parser<'arena> {
           SourceFile::Path(target_abspath),
           leveloffset,
           include_depth,
+          selection,
           buffer,
         );
         Ok(DirectiveAction::ReadNextLine)
@@ -132,6 +154,33 @@ impl<'arena> Parser<'arena> {
     }
   }
 
+  // NB: wasm has no filesystem, so there embedders must supply a resolver
+  #[cfg(not(target_arch = "wasm32"))]
+  fn default_include_resolver(&self) -> Option<Box<dyn IncludeResolver>> {
+    let base_dir = match self.lexer.source_file() {
+      SourceFile::Path(path) => Path::new(path.dirname()),
+      SourceFile::Stdin { cwd } => cwd.clone(),
+      SourceFile::Tmp => return None,
+    };
+    let safe_mode = self.document.meta.safe_mode;
+    Some(Box::new(FsResolver::new(base_dir, safe_mode)))
+  }
+
+  // NB: the same file may be included again with a different selection,
+  // e.g. a tagged region of the current document via `{docfile}`
+  fn include_selection(&self, attrs: &AttrList) -> Option<BumpString<'arena>> {
+    let mut selection = self.string("");
+    for name in ["lines", "tag", "tags"] {
+      if let Some(value) = attrs.named(name) {
+        selection.push_str(name);
+        selection.push('=');
+        selection.push_str(value);
+        selection.push(';');
+      }
+    }
+    (!selection.is_empty()).then_some(selection)
+  }
+
   fn valid_include_directive(
     &mut self,
     line: &mut Line<'arena>,
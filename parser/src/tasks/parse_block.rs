@@ -4,6 +4,16 @@ use ast::short::block::*;
 
 impl<'arena> Parser<'arena> {
   pub(crate) fn parse_block(&mut self) -> Result<Option<Block<'arena>>> {
+    let in_hidden_block = self.ctx.in_hidden_block;
+    let mut block = self.parse_block_content()?;
+    self.ctx.in_hidden_block = in_hidden_block;
+    if let Some(block) = &mut block {
+      self.register_block_anchor(block);
+    }
+    Ok(block)
+  }
+
+  fn parse_block_content(&mut self) -> Result<Option<Block<'arena>>> {
     let Some(mut lines) = self.read_lines()? else {
       return Ok(None);
     };
@@ -14,6 +24,9 @@ impl<'arena> Parser<'arena> {
     }
 
     let meta = self.parse_chunk_meta(&mut lines)?;
+    if meta.attrs.as_ref().is_some_and(|a| a.has_role("hidden")) {
+      self.ctx.in_hidden_block = true;
+    }
 
     match self.section_start_level(&lines, &meta) {
       Some(0) => {} // skip document titles
@@ -36,13 +49,18 @@ impl<'arena> Parser<'arena> {
     let first_token = lines.current_token().unwrap();
 
     if lines.is_block_macro() {
-      return match first_token.lexeme.as_str() {
-        "image:" => self.parse_image_block(lines, meta),
-        "toc:" => self.parse_toc_macro(first_token.loc, lines, meta),
-        _ => todo!("unhandled block macro type: `{:?}`", first_token.lexeme),
+      match first_token.lexeme.as_str() {
+        "audio:" => return self.parse_audio_block(lines, meta).map(Some),
+        "image:" => return self.parse_image_block(lines, meta).map(Some),
+        "pass:" => return self.parse_pass_block(lines, meta).map(Some),
+        "video:" => return self.parse_video_block(lines, meta).map(Some),
+        "toc:" => return self.parse_toc_macro(first_token.loc, lines, meta).map(Some),
+        // NB: inline-only macros (e.g. `stem::[x]`) fall through to a paragraph
+        _ => {}
       }
-      .map(Some);
-    } else if lines.starts_list() {
+    }
+
+    if lines.starts_list() {
       return self.parse_list(lines, Some(meta)).map(Some);
     } else if lines.current_satisfies(|line| line.is_heading()) {
       return self.parse_discrete_heading(lines, meta).map(Some);
@@ -160,6 +178,7 @@ impl<'arena> Parser<'arena> {
       Context::Listing
         | Context::Literal
         | Context::Passthrough
+        | Context::Stem
         | Context::Comment
         | Context::Verse
     ) {
@@ -224,6 +243,7 @@ impl<'arena> Parser<'arena> {
     line.discard_assert(Colon);
     let target = line.consume_macro_target(self.bump);
     let attrs = self.parse_block_attr_list(&mut line)?;
+    self.push_image_dependencies(&target, &attrs);
     Ok(Block {
       meta,
       context: Context::Image,
@@ -231,6 +251,60 @@ impl<'arena> Parser<'arena> {
     })
   }
 
+  fn parse_audio_block(
+    &mut self,
+    mut lines: ContiguousLines<'arena>,
+    meta: ChunkMeta<'arena>,
+  ) -> Result<Block<'arena>> {
+    let mut line = lines.consume_current().unwrap();
+    line.discard_assert(MacroName);
+    line.discard_assert(Colon);
+    let target = line.consume_macro_target(self.bump);
+    let attrs = self.parse_block_attr_list(&mut line)?;
+    Ok(Block {
+      meta,
+      context: Context::Audio,
+      content: Content::Empty(EmptyMetadata::Audio { target, attrs }),
+    })
+  }
+
+  fn parse_video_block(
+    &mut self,
+    mut lines: ContiguousLines<'arena>,
+    meta: ChunkMeta<'arena>,
+  ) -> Result<Block<'arena>> {
+    let mut line = lines.consume_current().unwrap();
+    line.discard_assert(MacroName);
+    line.discard_assert(Colon);
+    let target = line.consume_macro_target(self.bump);
+    let attrs = self.parse_block_attr_list(&mut line)?;
+    Ok(Block {
+      meta,
+      context: Context::Video,
+      content: Content::Empty(EmptyMetadata::Video { target, attrs }),
+    })
+  }
+
+  fn parse_pass_block(
+    &mut self,
+    mut lines: ContiguousLines<'arena>,
+    meta: ChunkMeta<'arena>,
+  ) -> Result<Block<'arena>> {
+    let mut line = lines.consume_current().unwrap();
+    line.discard_assert(MacroName);
+    line.discard_assert(Colon);
+    let _ = line.consume_optional_macro_target(self.bump);
+    line.discard_assert_last(CloseBracket);
+    let restore_subs = self.ctx.set_subs_for(Context::Passthrough, &meta);
+    let content = self.parse_inlines(&mut line.into_lines())?;
+    self.ctx.subs = restore_subs;
+    Ok(Block {
+      meta,
+      context: Context::Passthrough,
+      content: Content::Simple(content),
+    })
+  }
+
   fn parse_paragraph(
     &mut self,
     mut lines: ContiguousLines<'arena>,
@@ -318,10 +392,113 @@ impl<'arena> Parser<'arena> {
       content: Content::Empty(EmptyMetadata::None),
     });
   }
+
+  fn register_block_anchor(&mut self, block: &mut Block<'arena>) {
+    // NB: hidden blocks aren't rendered, so they mustn't consume a number
+    let hidden = self.ctx.in_hidden_block || block.has_role("hidden");
+    let captioned = block.meta.title.is_some() && !hidden;
+    let meta = &self.document.meta;
+    // NB: the backend renders captions from the numeral we assign here
+    let numeral = match block.context {
+      Context::Image if captioned && !meta.is_false("figure-caption") => {
+        let mut num_figures = self.ctx.num_figures.borrow_mut();
+        *num_figures += 1;
+        block.meta.numeral = Some(*num_figures);
+        Some(("figure-caption", *num_figures))
+      }
+      Context::Table
+        if captioned
+          && block.meta.attr_named("caption").is_none()
+          && !meta.is_false("table-caption") =>
+      {
+        let mut num_tables = self.ctx.num_tables.borrow_mut();
+        *num_tables += 1;
+        block.meta.numeral = Some(*num_tables);
+        Some(("table-caption", *num_tables))
+      }
+      Context::Example
+        if captioned
+          && !block.has_attr_option("collapsible")
+          && block.meta.attr_named("caption").is_none()
+          && !meta.is_false("example-caption") =>
+      {
+        let mut num_examples = self.ctx.num_examples.borrow_mut();
+        *num_examples += 1;
+        block.meta.numeral = Some(*num_examples);
+        Some(("example-caption", *num_examples))
+      }
+      Context::Stem if !hidden => match numbered_equations(block, meta) {
+        0 => None,
+        count => {
+          let mut num_equations = self.ctx.num_equations.borrow_mut();
+          block.meta.numeral = Some(*num_equations + 1);
+          *num_equations += count;
+          Some(("equation-caption", *num_equations + 1 - count))
+        }
+      },
+      _ => None,
+    };
+    let Some(attrs) = &block.meta.attrs else {
+      return;
+    };
+    let Some(id) = &attrs.id else {
+      return;
+    };
+    self.declare_id(id);
+    let anchor = Anchor {
+      reftext: attrs.named.get("reftext").cloned(),
+      title: block
+        .meta
+        .title
+        .clone()
+        .unwrap_or_else(|| InlineNodes::new(self.bump)),
+      numeral,
+    };
+    self
+      .document
+      .anchors
+      .borrow_mut()
+      .entry(id.src.clone())
+      .or_insert(anchor);
+  }
 }
 
 // tests
 
+/// how many equations MathJax will number in a stem block, following its
+/// `autoNumber` modes: `AMS` (the `eqnums` default) numbers only `equation`
+/// environments, `all` numbers every latexmath display, and asciimath
+/// blocks are never numbered
+fn numbered_equations(block: &Block, meta: &DocumentMeta) -> usize {
+  let Some(eqnums) = meta.str("eqnums").filter(|eqnums| *eqnums != "none") else {
+    return 0;
+  };
+  let style = block
+    .meta
+    .attrs
+    .as_ref()
+    .and_then(|attrs| attrs.str_positional_at(0));
+  let latexmath = match style {
+    Some("latexmath") => true,
+    Some("asciimath") => false,
+    _ => matches!(meta.str("stem"), Some("latexmath" | "latex" | "tex")),
+  };
+  let Content::Simple(nodes) = &block.content else {
+    return 0;
+  };
+  if !latexmath {
+    0
+  } else if eqnums.eq_ignore_ascii_case("all") {
+    1
+  } else {
+    nodes
+      .plain_text()
+      .concat()
+      .matches(r"\begin{equation}")
+      .count()
+  }
+}
+
 #[cfg(test)]
 mod tests {
   use super::*;
@@ -15,10 +15,30 @@ impl<'arena> Parser<'arena> {
     self.parse_doc_attrs(&mut block)?;
     self.parse_doc_title_author_revision(&mut block)?;
     self.parse_doc_attrs(&mut block)?;
+    self.setup_author_attrs();
     self.setup_toc();
     Ok(())
   }
 
+  // NB: an author set via `:author:` (w/out an author line)
+  // gets the same derived name attributes as an author line,
+  // and an `:email:` attr overrides the author line email
+  fn setup_author_attrs(&mut self) {
+    let meta = &self.document.meta;
+    let email = meta.str("email").map(|email| email.to_string());
+    if let Some(author) = meta.authors().first() {
+      if let Some(email) = email.filter(|email| author.email.as_ref() != Some(email)) {
+        self.document.meta.set_author_email(email);
+      }
+      return;
+    }
+    let Some(mut author) = meta.str("author").and_then(Author::from_fullname) else {
+      return;
+    };
+    author.email = email;
+    self.document.meta.add_author(author);
+  }
+
   fn setup_toc(&mut self) {
     let Some(toc_attr) = self.document.meta.get("toc") else {
       return;
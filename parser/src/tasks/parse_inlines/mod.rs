@@ -57,7 +57,7 @@ impl<'arena> Parser<'arena> {
           return Ok(acc.inlines);
         }
 
-        if line.may_contain_inline_pass() {
+        if line.may_contain_inline_pass() && subs.macros() {
           self.replace_inline_pass(&mut line, lines)?;
           break;
         }
@@ -77,7 +77,12 @@ impl<'arena> Parser<'arena> {
             self.parse_uri_scheme_macro(&token, &mut line, &mut acc)?
           }
 
-          MacroName if subs.macros() && line.continues_inline_macro() => {
+          MacroName
+            if subs.macros()
+              && line.continues_inline_macro()
+              && !matches!(token.lexeme.as_str(), "audio:" | "video:")
+              && (token.lexeme != "btn:" || self.document.meta.is_true("experimental")) =>
+          {
             let mut macro_loc = token.loc;
             let line_end = line.last_location().unwrap();
             acc.commit();
@@ -86,6 +91,7 @@ impl<'arena> Parser<'arena> {
                 let target = line.consume_macro_target(self.bump);
                 let attrs = self.parse_inline_attr_list(&mut line)?;
                 finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
+                self.push_image_dependencies(&target, &attrs);
                 acc.push_node(
                   Macro(Image { flow: Flow::Inline, target, attrs }),
                   macro_loc,
@@ -109,9 +115,15 @@ impl<'arena> Parser<'arena> {
                 lines.restore_if_nonempty(line);
                 let note = self.parse_inlines_until(lines, &[Kind(CloseBracket)])?;
                 extend(&mut macro_loc, &note, 1);
-                let number = {
+                let number = if id.is_some() && note.is_empty() {
+                  0 // a reference, resolved when evaluating
+                } else {
                   let mut num_footnotes = self.ctx.num_footnotes.borrow_mut();
                   *num_footnotes += 1;
+                  if let Some(id) = &id {
+                    let mut footnotes = self.document.footnotes.borrow_mut();
+                    footnotes.insert(id.src.clone(), *num_footnotes);
+                  }
                   *num_footnotes
                 };
                 acc.push_node(Macro(Footnote { number, id, text: note }), macro_loc);
@@ -122,7 +134,9 @@ impl<'arena> Parser<'arena> {
                 if id.src.starts_with('#') {
                   id.drop_first();
                 }
-                self.ctx.xrefs.borrow_mut().insert(id.src.clone(), id.loc);
+                if InterdocTarget::parse(&id.src).is_none() {
+                  self.ctx.xrefs.borrow_mut().insert(id.src.clone(), id.loc);
+                }
                 lines.restore_if_nonempty(line);
                 let nodes = self.parse_inlines_until(lines, &[Kind(CloseBracket)])?;
                 let linktext = if nodes.is_empty() {
@@ -170,6 +184,18 @@ impl<'arena> Parser<'arena> {
                 finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
                 acc.push_node(Macro(Button(btn)), macro_loc);
               }
+              "stem:" | "latexmath:" | "asciimath:" => {
+                let name = match token.lexeme.as_str() {
+                  "latexmath:" => "latexmath",
+                  "asciimath:" => "asciimath",
+                  _ => "stem",
+                };
+                line.discard_assert(OpenBracket);
+                let text = line.consume_to_string_until_nonescaped(CloseBracket, self.bump);
+                line.discard_assert(CloseBracket);
+                finish_macro(&line, &mut macro_loc, line_end, &mut acc.text);
+                acc.push_node(Macro(Stem { name, text }), macro_loc);
+              }
               "menu:" => {
                 let first = line.consume_macro_target(self.bump);
                 let mut items = bvec![in self.bump; first];
@@ -194,20 +220,54 @@ impl<'arena> Parser<'arena> {
               }
               "anchor:" => {
                 let id = line.consume_macro_target(self.bump);
+                self.declare_id(&id);
                 let mut attrs = self.parse_inline_attr_list(&mut line)?;
-                self.document.anchors.borrow_mut().insert(
-                  id.src.clone(),
-                  Anchor {
+                self
+                  .document
+                  .anchors
+                  .borrow_mut()
+                  .entry(id.src.clone())
+                  .or_insert(Anchor {
                     reftext: attrs.take_positional(0),
                     title: InlineNodes::new(self.bump),
-                  },
-                );
+                    numeral: None,
+                  });
                 acc.push_node(InlineAnchor(id.src), id.loc);
               }
               _ => todo!("unhandled macro type: `{}`", token.lexeme),
             }
           }
 
+          MacroName
+            if subs.macros()
+              && matches!(token.lexeme.as_str(), "stem:" | "latexmath:" | "asciimath:")
+              && line.current_is(OpenBracket) =>
+          {
+            // NB: inline math may span lines, join them up to the closing `]`
+            if lines.any(|next| next.contains_nonescaped(CloseBracket)) {
+              let mut joined = Line::empty(self.bump);
+              joined.push(token);
+              line.into_iter().for_each(|t| joined.push(t));
+              while !joined.continues_inline_macro() {
+                let Some(next) = lines.consume_current() else {
+                  break;
+                };
+                let loc = joined.last_loc().unwrap();
+                let newline = SourceLocation::new_depth(loc.end, loc.end + 1, loc.include_depth);
+                joined.push(self.token(TokenKind::Newline, "\n", newline));
+                next.into_iter().for_each(|t| joined.push(t));
+              }
+              line = joined;
+              continue;
+            }
+            self.warn_at(
+              "Unterminated inline math, no closing `]` found",
+              token.loc.start,
+              line.last_location().unwrap().end,
+            );
+            acc.text.push_token(&token);
+          }
+
           AttrRef if !subs.attr_refs() => {
             // turns out we didn't need to resolve the attr ref
             // probably because we're inside a pass macro, so
@@ -275,7 +335,7 @@ impl<'arena> Parser<'arena> {
             acc.push_node(SpecialChar(SpecialCharKind::GreaterThan), end);
           }
 
-          LessThan if line.continues_xref_shorthand() => {
+          LessThan if subs.macros() && line.continues_xref_shorthand() => {
             let mut loc = token.loc;
             line.discard_assert(LessThan);
             if line.current_is(Hash) {
@@ -283,7 +343,9 @@ impl<'arena> Parser<'arena> {
             }
             let mut inner = line.extract_line_before(&[Kind(GreaterThan), Kind(GreaterThan)]);
             let id = inner.consume_to_string_until(Comma, self.bump);
-            self.ctx.xrefs.borrow_mut().insert(id.src.clone(), id.loc);
+            if InterdocTarget::parse(&id.src).is_none() {
+              self.ctx.xrefs.borrow_mut().insert(id.src.clone(), id.loc);
+            }
             let mut linktext = None;
             if !inner.is_empty() {
               inner.discard_assert(Comma);
@@ -299,6 +361,7 @@ impl<'arena> Parser<'arena> {
           LessThan
             if subs.macros()
               && line.current_token().is(UriScheme)
+              && !line.current_token().matches(UriScheme, "mailto:")
               && line.no_whitespace_until(GreaterThan) =>
           {
             acc.push_node(Discarded, token.loc);
@@ -386,13 +449,24 @@ impl<'arena> Parser<'arena> {
             line.discard_assert(Hash);
             parse_token.kind = Hash;
             let span = |inner| TextSpan(attr_list, inner);
-            self.parse_node(span, [Kind(Hash)], &parse_token, &mut acc, line, lines)?;
+            if starts_unconstrained(&[Kind(Hash); 2], &parse_token, &line, lines) {
+              self.parse_node(span, [Kind(Hash); 2], &parse_token, &mut acc, line, lines)?;
+            } else {
+              self.parse_node(span, [Kind(Hash)], &parse_token, &mut acc, line, lines)?;
+            }
             if let Some(InlineNode { content: TextSpan(attrs, nodes), .. }) = acc.inlines.last() {
               if let Some(id) = &attrs.id {
-                self.document.anchors.borrow_mut().insert(
-                  id.src.clone(),
-                  Anchor { reftext: None, title: nodes.clone() },
-                );
+                self.declare_id(id);
+                self
+                  .document
+                  .anchors
+                  .borrow_mut()
+                  .entry(id.src.clone())
+                  .or_insert(Anchor {
+                    reftext: None,
+                    title: nodes.clone(),
+                    numeral: None,
+                  });
               }
             }
             break;
@@ -405,13 +479,17 @@ impl<'arena> Parser<'arena> {
           {
             line.discard(1); // second `[`
             if let Some(AnchorSrc { id, reftext, loc }) = self.parse_inline_anchor(&mut line)? {
-              self.document.anchors.borrow_mut().insert(
-                id.src.clone(),
-                Anchor {
+              self.declare_id(&id);
+              self
+                .document
+                .anchors
+                .borrow_mut()
+                .entry(id.src.clone())
+                .or_insert(Anchor {
                   reftext,
                   title: InlineNodes::new(self.bump),
-                },
-              );
+                  numeral: None,
+                });
               acc.push_node(InlineAnchor(id.src), loc);
             } else {
               acc.text.push_token(&token);
@@ -542,7 +620,7 @@ impl<'arena> Parser<'arena> {
             break;
           }
 
-          Plus if token.len() == 3 && contains_len(Plus, 3, &line, lines) => {
+          Plus if subs.macros() && token.len() == 3 && contains_len(Plus, 3, &line, lines) => {
             self.ctx.subs = Substitutions::none();
             self.parse_node(
               InlinePassthru,
@@ -615,7 +693,9 @@ impl<'arena> Parser<'arena> {
             acc.push_node(MultiCharWhitespace(token.lexeme), token.loc);
           }
 
-          Whitespace if line.current_is(Plus) && line.num_tokens() == 1 => {
+          Whitespace
+            if subs.post_replacement() && line.current_is(Plus) && line.num_tokens() == 1 =>
+          {
             let mut loc = token.loc;
             line.discard_assert(Plus);
             loc.end += 2; // plus and newline
@@ -627,14 +707,19 @@ impl<'arena> Parser<'arena> {
 
           Discard | AttrRef => acc.text.loc = token.loc.clamp_end(),
 
-          Backslash if !line.is_empty() => {
+          // in verbatim/raw contexts escapes are not consumed, the source is emitted as-is
+          Backslash
+            if !line.is_empty()
+              && (subs.consumes_escapes()
+                || (subs.callouts() && line.current_is(CalloutNumber))) =>
+          {
             acc.push_node(Discarded, token.loc);
             // pushing the next token as text prevents recognizing the pattern
             let next_token = line.consume_current().unwrap();
             acc.text.push_token(&next_token);
           }
 
-          _ if subs.macros() && token.is(UriScheme) => {
+          _ if subs.macros() && token.is(UriScheme) && !token.matches(UriScheme, "mailto:") => {
             let mut loc = token.loc;
             let line_end = line.last_location().unwrap();
             let target = line.consume_url(Some(&token), None, self.bump);
@@ -688,10 +773,17 @@ impl<'arena> Parser<'arena> {
     let mut macro_loc = token.loc;
     let line_end = line.last_location().unwrap();
     acc.commit();
-    let target = line.consume_url(Some(token), Some(OpenBracket), self.bump);
+    let mailto = token.matches(UriScheme, "mailto:");
+    // the backend adds the `mailto:` scheme, same as for bare emails
+    let target = line.consume_url((!mailto).then_some(token), Some(OpenBracket), self.bump);
     line.discard_assert(OpenBracket);
     let line_has_caret = line.contains(Caret);
-    let mut attrs = self.parse_link_macro_attr_list(line)?;
+    let mut attrs = if mailto && line.contains(Comma) {
+      // mailto:me@x.com[text,subject,body]
+      self.parse_inline_attr_list(line)?
+    } else {
+      self.parse_link_macro_attr_list(line)?
+    };
     let mut caret = false;
     if line_has_caret {
       caret = link_macro_blank_window_shorthand(&mut attrs);
@@ -1243,6 +1335,17 @@ mod tests {
           node!(" bar"; 14..18),
         ],
       ),
+      (
+        // special chars never remain inside text nodes
+        "a & b, x&y",
+        nodes![
+          node!("a "; 0..2),
+          node!(SpecialChar(SpecialCharKind::Ampersand), 2..3),
+          node!(" b, x"; 3..8),
+          node!(SpecialChar(SpecialCharKind::Ampersand), 8..9),
+          node!("y"; 9..10),
+        ],
+      ),
       (
         "foo #bar#",
         nodes![
@@ -1506,7 +1609,48 @@ mod tests {
         )],
       ),
     ];
-    run(cases);
+    for (input, expected) in cases {
+      let mut parser = test_parser!(input);
+      parser
+        .document
+        .meta
+        .insert_job_attr("experimental", JobAttr::readonly(true))
+        .unwrap();
+      let mut block = parser.read_lines().unwrap().unwrap();
+      let inlines = parser.parse_inlines(&mut block).unwrap();
+      expect_eq!(inlines, expected, from: input);
+    }
+  }
+
+  #[test]
+  fn test_stem_macros() {
+    run(vec![
+      (
+        "stem:[x < 1]",
+        nodes![node!(
+          Macro(Stem {
+            name: "stem",
+            text: src!("x < 1", 6..11)
+          }),
+          0..12,
+        )],
+      ),
+      (
+        "latexmath:[[a\\]]",
+        nodes![node!(
+          Macro(Stem {
+            name: "latexmath",
+            text: src!("[a]", 11..15)
+          }),
+          0..16,
+        )],
+      ),
+    ]);
+  }
+
+  #[test]
+  fn test_button_macro_requires_experimental() {
+    run(vec![("btn:[Open]", nodes![node!("btn:[Open]"; 0..10)])]);
   }
 
   fn run(cases: Vec<(&str, InlineNodes)>) {
@@ -21,11 +21,12 @@ impl<'arena> Parser<'arena> {
       items.push(item);
     }
     self.ctx.list.stack.pop();
-    if variant == ListVariant::Callout {
-      self.ctx.advance_callout_list(self.bump);
+    let start = items.first().unwrap().loc_start();
+    if variant == ListVariant::Callout && !self.ctx.advance_callout_list(self.bump) {
+      self.err_line_starting("Too many callout lists, callout ids will repeat", start)?;
     }
 
-    let meta = meta.unwrap_or_else(|| ChunkMeta::empty(items.first().unwrap().loc_start()));
+    let meta = meta.unwrap_or_else(|| ChunkMeta::empty(start));
     Ok(Block {
       meta,
       context: variant.to_context(),
@@ -43,9 +43,17 @@ impl<'arena> Parser<'arena> {
 
     let heading = self.parse_inlines(&mut heading_line.into_lines())?;
     if !out_of_sequence {
-      self.push_toc_node(level, &heading, id.as_ref());
+      let special_sect = meta.attrs.as_ref().and_then(|a| a.str_positional_at(0));
+      let unnumbered = meta
+        .attrs
+        .as_ref()
+        .is_some_and(|a| a.has_option("unnumbered"));
+      self.push_toc_node(level, &heading, id.as_ref(), special_sect, unnumbered);
     }
 
+    if let Some(explicit_id) = meta.attrs.as_ref().and_then(|attrs| attrs.id.as_ref()) {
+      self.declare_id(explicit_id);
+    }
     if let Some(id) = &id {
       let reftext = meta
         .attrs
@@ -56,7 +64,12 @@ impl<'arena> Parser<'arena> {
         .document
         .anchors
         .borrow_mut()
-        .insert(id.clone(), Anchor { reftext, title: heading.clone() });
+        .entry(id.clone())
+        .or_insert(Anchor {
+          reftext,
+          title: heading.clone(),
+          numeral: None,
+        });
     }
 
     self.restore_lines(lines);
@@ -74,6 +87,8 @@ impl<'arena> Parser<'arena> {
     level: u8,
     heading: &InlineNodes<'arena>,
     as_ref: Option<&BumpString<'arena>>,
+    special_sect: Option<&str>,
+    unnumbered: bool,
   ) {
     let Some(toc) = self.document.toc.as_mut() else {
       return;
@@ -92,6 +107,8 @@ impl<'arena> Parser<'arena> {
       level,
       title: heading.clone(),
       id: as_ref.cloned(),
+      special_sect: special_sect.map(|s| BumpString::from_str_in(s, self.bump)),
+      unnumbered,
       children: BumpVec::new_in(self.bump),
     });
   }
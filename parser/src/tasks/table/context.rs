@@ -1,4 +1,5 @@
 use std::collections::HashSet;
+use std::ops::Range;
 
 use super::DataFormat;
 use crate::internal::*;
@@ -46,6 +47,9 @@ pub struct ParseCellData<'arena> {
   pub cell_tokens: Line<'arena>,
   pub cell_spec: CellSpec,
   pub col_spec: Option<ColSpec>,
+  // entries in `ParseContext::declared_ids` from the first parse,
+  // which are superseded if the cell is reparsed as a header cell
+  pub declared_ids: Range<usize>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
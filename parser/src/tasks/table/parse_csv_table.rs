@@ -144,6 +144,45 @@ mod tests {
     );
   }
 
+  #[test]
+  fn csv_escaped_quotes() {
+    assert_table!(
+      adoc! {r#"
+        ,===
+        "he said ""hi""",b
+        ,===
+      "#},
+      Table {
+        col_widths: ColWidths::new(vecb![w(1), w(1)]),
+        rows: vecb![Row::new(vecb![
+          cell!(d: "he said \"hi\"", 6..20),
+          cell!(d: "b", 22..23),
+        ])],
+        ..empty_table!()
+      }
+    );
+  }
+
+  #[test]
+  fn tsv_quoted_tab() {
+    assert_table!(
+      adoc! {"
+        [format=tsv]
+        |===
+        \"a\tb\"\tc
+        |===
+      "},
+      Table {
+        col_widths: ColWidths::new(vecb![w(1), w(1)]),
+        rows: vecb![Row::new(vecb![
+          cell!(d: "a\tb", 19..22),
+          cell!(d: "c", 24..25),
+        ])],
+        ..empty_table!()
+      }
+    );
+  }
+
   #[test]
   fn csv_empty_trailing_cell() {
     assert_table!(
@@ -106,7 +106,7 @@ impl<'arena> Parser<'arena> {
         ctx.counting_cols = false
       }
       end = token.loc.end;
-      if !token.is(TokenKind::Backslash) {
+      if !token.is(TokenKind::Backslash) || !Self::dsv_escapable(tokens, ctx) {
         cell_tokens.push(token);
       } else if let Some(next) = tokens.consume_current() {
         end = next.loc.end;
@@ -115,6 +115,19 @@ impl<'arena> Parser<'arena> {
     }
   }
 
+  // NB: only the delimiter and a backslash itself can be escaped,
+  // any other backslash is kept, e.g. `C:\Users\bob`
+  fn dsv_escapable(tokens: &TableTokens, ctx: &TableContext) -> bool {
+    let Some(next) = tokens.current() else {
+      return false;
+    };
+    let is_separator = match ctx.cell_separator_tokenkind {
+      Some(tokenkind) => next.is(tokenkind),
+      None => next.lexeme.starts_with(ctx.cell_separator),
+    };
+    next.is(TokenKind::Backslash) || is_separator
+  }
+
   pub(crate) fn consume_dsv_delimiter(
     &self,
     tokens: &mut TableTokens,
@@ -205,6 +218,26 @@ mod tests {
     );
   }
 
+  #[test]
+  fn dsv_escaped_backslash() {
+    assert_table!(
+      adoc! {r#"
+        :===
+        one\:two:a\\:b
+        :===
+      "#},
+      Table {
+        col_widths: ColWidths::new(vecb![w(1), w(1), w(1)]),
+        rows: vecb![Row::new(vecb![
+          cell!(d: "one:two", 5..13),
+          cell!(d: "a\\", 14..17),
+          cell!(d: "b", 18..19),
+        ])],
+        ..empty_table!()
+      }
+    );
+  }
+
   #[test]
   fn dsv_table_trailing_sep_is_empty_cell() {
     assert_table!(
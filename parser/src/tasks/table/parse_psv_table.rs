@@ -35,6 +35,7 @@ impl<'arena> Parser<'arena> {
     ctx: &mut TableContext<'arena>,
   ) -> Result<Option<Row<'arena>>> {
     let mut cells = bvec![in self.bump];
+    let row_start = tokens.current().map(|token| token.loc.start);
     let mut num_effective_cells = ctx.row_phantom_cells();
     'outer: while let Some((cell, dupe)) = self.parse_psv_table_cell(tokens, ctx, cells.len())? {
       if dupe > 1 {
@@ -56,6 +57,18 @@ impl<'arena> Parser<'arena> {
     }
     if cells.is_empty() {
       Ok(None)
+    } else if num_effective_cells < ctx.num_cols && tokens.is_empty() {
+      // NB: like asciidoctor, drop the incomplete last row and keep going
+      let start = row_start.unwrap_or_default();
+      self.warn_at(
+        format!(
+          "Dropping incomplete table row, expected {} cells but found {}",
+          ctx.num_cols, num_effective_cells
+        ),
+        start,
+        start + 1,
+      );
+      Ok(None)
     } else {
       ctx.effective_row_idx += 1;
       Ok(Some(Row::new(cells)))
@@ -835,7 +848,8 @@ mod tests {
         meta: ChunkMeta {
           attrs: None,
           title: Some(just!("Simple psv table", 1..17)),
-          start: 0
+          start: 0,
+          numeral: Some(1),
         },
         content: BlockContent::Table(Table {
           col_widths: ColWidths::new(vecb![w(1), w(1), w(1)]),
@@ -907,4 +921,29 @@ mod tests {
         | ^^ Cell column span (3) exceeds number of columns (2)
     "}
   );
+
+  #[test]
+  fn incomplete_last_row_dropped_w_warning() {
+    let input = adoc! {r#"
+      [cols="2*"]
+      |===
+      |a |b
+      |c
+      |===
+    "#};
+    let result = test_parser!(input).parse().expect("expected parse success");
+    expect_eq!(result.warnings.len(), 1, from: input);
+    expect_eq!(
+      result.warnings[0].plain_text(),
+      error! {r"
+         --> test.adoc:4:1
+          |
+        4 | |c
+          | ^ Dropping incomplete table row, expected 2 cells but found 1
+      "},
+      from: input
+    );
+    let table = parse_table!(input);
+    expect_eq!(table.rows.len(), 1, from: input);
+  }
 }
@@ -199,22 +199,28 @@ impl<'arena> Parser<'arena> {
     }
 
     let repeat = cell_spec.duplication.unwrap_or(1);
+    let may_reparse = ctx.header_row.is_unknown() || trimmed_implicit_header;
+    let declared_from = self.ctx.declared_ids.borrow().len();
     if cell_style == CellContentStyle::AsciiDoc {
-      if ctx.header_row.is_unknown() || trimmed_implicit_header {
-        ctx.header_reparse_cells.push(ParseCellData {
-          cell_tokens: cell_tokens.clone(),
-          cell_spec: cell_spec.clone(),
-          col_spec: col_spec.cloned(),
-        });
-      }
+      let reparse_data = may_reparse.then(|| ParseCellData {
+        cell_tokens: cell_tokens.clone(),
+        cell_spec: cell_spec.clone(),
+        col_spec: col_spec.cloned(),
+        declared_ids: 0..0,
+      });
       cell_tokens.trim_for_cell(cell_style);
       cell_tokens.remove_resolved_attr_refs();
       let cell_parser = self.cell_parser(cell_tokens.into_bytes(), loc.start);
-      return match cell_parser.parse() {
-        Ok(ParseResult { document, warnings }) => {
-          if !warnings.is_empty() {
-            self.errors.borrow_mut().extend(warnings);
-          }
+      let result = cell_parser.parse();
+      if let Some(mut data) = reparse_data {
+        data.declared_ids = declared_from..self.ctx.declared_ids.borrow().len();
+        ctx.header_reparse_cells.push(data);
+      }
+      return match result {
+        Ok(ParseResult { document, warnings, dependencies }) => {
+          self.warnings.borrow_mut().extend(warnings);
+          // NB: the cell shares our dependency list, so it took ours as well
+          self.ctx.dependencies.replace(dependencies);
           let content = CellContent::AsciiDoc(document);
           let cell = Cell::new(content, cell_spec, col_spec.cloned());
           Ok(Some((cell, repeat)))
@@ -234,13 +240,15 @@ impl<'arena> Parser<'arena> {
       cell_tokens,
       cell_spec,
       col_spec: col_spec.cloned(),
+      declared_ids: 0..0,
     };
-    if cell_style == CellContentStyle::Literal
-      && (ctx.header_row.is_unknown() || trimmed_implicit_header)
-    {
-      ctx.header_reparse_cells.push(cell_data.clone());
-    }
+    let reparse_data =
+      (cell_style == CellContentStyle::Literal && may_reparse).then(|| cell_data.clone());
     let cell = self.parse_non_asciidoc_cell(cell_data, cell_style)?;
+    if let Some(mut data) = reparse_data {
+      data.declared_ids = declared_from..self.ctx.declared_ids.borrow().len();
+      ctx.header_reparse_cells.push(data);
+    }
     Ok(Some((cell, repeat)))
   }
 
@@ -256,12 +264,14 @@ impl<'arena> Parser<'arena> {
     row: &mut Row<'arena>,
     ctx: &mut TableContext<'arena>,
   ) -> Result<()> {
+    let mut superseded_ids = Vec::new();
     for idx in 0..row.cells.len() {
       let mut content = CellContent::Literal(InlineNodes::new(self.bump));
       std::mem::swap(&mut row.cells[idx].content, &mut content);
       row.cells[idx].content = match content {
         CellContent::AsciiDoc(_) | CellContent::Literal(_) => {
           let data = ctx.header_reparse_cells.remove(0);
+          superseded_ids.push(data.declared_ids.clone());
           let cell = self.parse_non_asciidoc_cell(data, CellContentStyle::Default)?;
           cell.content
         }
@@ -272,6 +282,11 @@ impl<'arena> Parser<'arena> {
         content => content,
       }
     }
+    // NB: ranges are ascending, and reparsing only appends past them
+    let mut declared_ids = self.ctx.declared_ids.borrow_mut();
+    for range in superseded_ids.into_iter().rev() {
+      declared_ids.drain(range);
+    }
     Ok(())
   }
 
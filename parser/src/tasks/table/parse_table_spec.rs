@@ -367,6 +367,89 @@ mod tests {
           8,
         )),
       ),
+      (
+        '|',
+        "^a|foo",
+        "foo",
+        Some((
+          CellSpec {
+            h_align: Some(HorizontalAlignment::Center),
+            style: Some(CellContentStyle::AsciiDoc),
+            ..CellSpec::default()
+          },
+          3,
+        )),
+      ),
+      (
+        '|',
+        "2+^.>a|foo",
+        "foo",
+        Some((
+          CellSpec {
+            col_span: Some(2),
+            h_align: Some(HorizontalAlignment::Center),
+            v_align: Some(VerticalAlignment::Bottom),
+            style: Some(CellContentStyle::AsciiDoc),
+            ..CellSpec::default()
+          },
+          7,
+        )),
+      ),
+      (
+        '|',
+        "2*.^l|foo",
+        "foo",
+        Some((
+          CellSpec {
+            duplication: Some(2),
+            v_align: Some(VerticalAlignment::Middle),
+            style: Some(CellContentStyle::Literal),
+            ..CellSpec::default()
+          },
+          6,
+        )),
+      ),
+      (
+        '|',
+        "2.3+>e|foo",
+        "foo",
+        Some((
+          CellSpec {
+            col_span: Some(2),
+            row_span: Some(3),
+            h_align: Some(HorizontalAlignment::Right),
+            style: Some(CellContentStyle::Emphasis),
+            ..CellSpec::default()
+          },
+          7,
+        )),
+      ),
+      (
+        '|',
+        ">d|foo",
+        "foo",
+        Some((
+          CellSpec {
+            h_align: Some(HorizontalAlignment::Right),
+            style: Some(CellContentStyle::Default),
+            ..CellSpec::default()
+          },
+          3,
+        )),
+      ),
+      (
+        '|',
+        "<h|foo",
+        "foo",
+        Some((
+          CellSpec {
+            h_align: Some(HorizontalAlignment::Left),
+            style: Some(CellContentStyle::Header),
+            ..CellSpec::default()
+          },
+          3,
+        )),
+      ),
     ];
 
     let parser = test_parser!("");
@@ -396,6 +479,20 @@ mod tests {
       ),
       ("1", &[ColSpec::default()]),
       ("~", &[ColSpec { width: Auto, ..ColSpec::default() }]),
+      (
+        "1,~,2",
+        &[
+          ColSpec {
+            width: Proportional(1),
+            ..ColSpec::default()
+          },
+          ColSpec { width: Auto, ..ColSpec::default() },
+          ColSpec {
+            width: Proportional(2),
+            ..ColSpec::default()
+          },
+        ],
+      ),
       (
         ">",
         &[ColSpec {
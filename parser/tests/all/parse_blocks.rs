@@ -305,6 +305,36 @@ fn test_parse_image_block() {
   );
 }
 
+#[test]
+fn test_parse_audio_block() {
+  assert_block!(
+    "audio::clip.ogg[]\n\n",
+    Block {
+      context: Context::Audio,
+      content: Content::Empty(EmptyMetadata::Audio {
+        target: src!("clip.ogg", 7..15),
+        attrs: attr_list!(15..17),
+      }),
+      ..empty_block!(0)
+    }
+  );
+}
+
+#[test]
+fn test_parse_video_block() {
+  assert_block!(
+    "video::movie.mp4[]\n\n",
+    Block {
+      context: Context::Video,
+      content: Content::Empty(EmptyMetadata::Video {
+        target: src!("movie.mp4", 7..16),
+        attrs: attr_list!(16..18),
+      }),
+      ..empty_block!(0)
+    }
+  );
+}
+
 #[test]
 fn test_parse_delimited_open_block() {
   assert_block!(
@@ -22,6 +22,19 @@ fn test_parse_callout() {
   );
 }
 
+#[test]
+fn test_parse_many_callout_lists() {
+  let input = "----\nfoo <1>\n----\n<1> bar\n\n".repeat(300);
+  let document = test_parser!(&input).parse().unwrap().document;
+  let DocContent::Blocks(blocks) = &document.content else {
+    panic!("expected blocks");
+  };
+  let Some(BlockContent::Simple(nodes)) = blocks.iter().rev().nth(1).map(|b| &b.content) else {
+    panic!("expected simple listing block");
+  };
+  assert_eq!(nodes.last().unwrap().content, callout(1, 299, 0));
+}
+
 #[test]
 fn test_parse_callout_sans_delimiter() {
   let input = adoc! {r#"
@@ -296,7 +309,7 @@ fn test_globally_unique_callouts() {
   );
 }
 
-const fn callout<'arena>(num: u8, list_idx: u8, idx: u8) -> Inline<'arena> {
+const fn callout<'arena>(num: u8, list_idx: u16, idx: u8) -> Inline<'arena> {
   CalloutNum(Callout {
     list_idx,
     callout_idx: idx,
@@ -1,3 +1,5 @@
+use std::path::PathBuf;
+
 use asciidork_ast::prelude::*;
 use asciidork_meta::{JobAttr, JobSettings};
 use asciidork_parser::includes::*;
@@ -328,10 +330,11 @@ fn include_resolver_error_bad_encoding() {
 
 #[test]
 fn include_resolver_error_no_resolver() {
-  let mut parser = test_parser!("include::file.adoc[]");
+  let input = "include::file.adoc[]";
+  let mut parser = Parser::from_str(input, SourceFile::Tmp, leaked_bump());
   parser.apply_job_settings(JobSettings::r#unsafe());
   let expected_err = error! {"
-     --> test.adoc:1:1
+     --> <temp-buffer>:1:1
       |
     1 | include::file.adoc[]
       | ^^^^^^^^^ No resolver supplied for include directive
@@ -339,6 +342,37 @@ fn include_resolver_error_no_resolver() {
   expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected_err);
 }
 
+#[test]
+fn default_resolver_reads_from_filesystem() {
+  let input = "include::inc.adoc[]";
+  let file = SourceFile::Path(Path::new("tests/fixtures/doc.adoc"));
+  let mut parser = Parser::from_str(input, file, leaked_bump());
+  parser.apply_job_settings(JobSettings::safe());
+  let document = parser.parse().unwrap().document;
+  expect_eq!(
+    document.content,
+    DocContent::Blocks(vecb![Block {
+      content: BlockContent::Simple(nodes![node!("included from disk"; 0..18, depth: 1)]),
+      ..empty_block!(0)
+    }])
+  );
+}
+
+#[test]
+fn default_resolver_jailed_to_base_dir() {
+  let input = "include::../../Cargo.toml[]";
+  let file = SourceFile::Path(Path::new("tests/fixtures/doc.adoc"));
+  let mut parser = Parser::from_str(input, file, leaked_bump());
+  parser.apply_job_settings(JobSettings::safe());
+  let expected_err = error! {"
+     --> doc.adoc:1:10
+      |
+    1 | include::../../Cargo.toml[]
+      |          ^^^^^^^^^^^^^^^^ Include resolver error: Cannot include files outside of the base directory
+  "};
+  expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected_err);
+}
+
 #[test]
 fn include_resolver_error_uri_read_not_supported() {
   let mut parser = test_parser!("include::http://a.com/b[]");
@@ -425,6 +459,46 @@ fn max_include_depth_rel() {
   expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected, from: input);
 }
 
+#[test]
+fn circular_include() {
+  let input = "include::a.adoc[]\n";
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(CyclicResolver));
+  let expected = error! {"
+     --> b.adoc:2:10
+      |
+    2 | include::a.adoc[]
+      |          ^^^^^^ Circular include of `a.adoc` detected
+  "};
+  expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected, from: input);
+}
+
+#[test]
+fn self_include_w_tag_not_circular() {
+  let input = "include::other.adoc[]\n";
+  let other = "// tag::x[]\nfoo\n// end::x[]\n\ninclude::other.adoc[tag=x]\n";
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(NestedResolver(vec![other, other])));
+  let result = parser.parse().expect("expected parse success");
+  assert!(result.warnings.is_empty());
+}
+
+const SELF_INCLUDE_SAME_TAG: &str = "// tag::x[]\ninclude::test.adoc[tag=x]\n// end::x[]\n";
+
+assert_error!(
+  self_include_w_same_tag_circular,
+  resolving: SELF_INCLUDE_SAME_TAG.as_bytes(),
+  SELF_INCLUDE_SAME_TAG,
+  error! {"
+     --> test.adoc:1:10
+      |
+    1 | include::test.adoc[tag=x]
+      |          ^^^^^^^^^ Circular include of `/test.adoc` detected
+  "}
+);
+
 #[test]
 fn max_include_depth_nested_depth_1() {
   let input = "include::file.adoc[depth=1]\n";
@@ -502,6 +576,50 @@ fn include_resolution_para_newlines_edge_case() {
   expect_eq!(parser.parse().err().unwrap()[0].plain_text(), expected, from: input);
 }
 
+#[test]
+fn records_include_and_image_dependencies() {
+  let input = adoc! {"
+    = Document Title
+    :stylesdir: css
+    :stylesheet: site.css
+
+    include::chapter-1.adoc[]
+
+    include::chapter-2.adoc[]
+
+    image::diagram.png[]
+
+    image::https://example.com/remote.png[]
+
+    image::ftp://example.com/remote.png[]
+  "};
+  let mut parser = test_parser!(input);
+  parser.apply_job_settings(JobSettings::r#unsafe());
+  parser.set_resolver(Box::new(NestedResolver(vec![
+    "Chapter 1 with image:icon.png[]\n",
+    "Chapter 2\n",
+  ])));
+  let result = parser.parse().unwrap();
+  expect_eq!(
+    result.dependencies(),
+    vec![
+      PathBuf::from("css/site.css"),
+      PathBuf::from("chapter-1.adoc"),
+      PathBuf::from("icon.png"),
+      PathBuf::from("chapter-2.adoc"),
+      PathBuf::from("diagram.png"),
+    ]
+  );
+  expect_eq!(
+    result.assets(),
+    vec![
+      PathBuf::from("css/site.css"),
+      PathBuf::from("icon.png"),
+      PathBuf::from("diagram.png"),
+    ]
+  );
+}
+
 // test resolvers
 
 struct AssertResolver {
@@ -566,6 +684,28 @@ impl IncludeResolver for InfiniteResolver {
   }
 }
 
+// `a.adoc` and `b.adoc` include each other
+struct CyclicResolver;
+
+impl IncludeResolver for CyclicResolver {
+  fn resolve(
+    &mut self,
+    target: IncludeTarget,
+    buffer: &mut dyn IncludeBuffer,
+  ) -> std::result::Result<usize, ResolveError> {
+    let other = if target.path().to_string() == "a.adoc" { "b" } else { "a" };
+    let file = format!("{}\ninclude::{}.adoc[]\n", target.path(), other);
+    let file_bytes = file.as_bytes();
+    buffer.initialize(file_bytes.len());
+    let dest = buffer.as_bytes_mut();
+    dest.copy_from_slice(file_bytes);
+    Ok(file_bytes.len())
+  }
+  fn get_base_dir(&self) -> Option<String> {
+    Some(String::new())
+  }
+}
+
 struct NestedResolver(Vec<&'static str>);
 
 impl IncludeResolver for NestedResolver {
@@ -179,3 +179,56 @@ assert_error!(
       |   ^^^ Invalid cross reference, no anchor found for `foo`
   "}
 );
+
+assert_warning!(
+  duplicate_block_ids,
+  adoc! {"
+    [#dupe]
+    foo
+
+    [#dupe]
+    bar
+  "},
+  error! {r"
+     --> test.adoc:4:3
+      |
+    4 | [#dupe]
+      |   ^^^^ Duplicate id `dupe`, first declared on line 1
+  "}
+);
+
+assert_warning!(
+  unterminated_inline_stem,
+  adoc! {"
+    Solve stem:[x + 1 = 2 now
+
+    next para
+  "},
+  error! {r"
+     --> test.adoc:1:7
+      |
+    1 | Solve stem:[x + 1 = 2 now
+      |       ^^^^^^^^^^^^^^^^^^^ Unterminated inline math, no closing `]` found
+  "}
+);
+
+assert_warning!(
+  duplicate_id_in_reparsed_header_cell,
+  adoc! {"
+    [cols=1a]
+    |===
+    |[[dupe]]* header
+
+    | AsciiDoc
+    |===
+
+    [#dupe]
+    para
+  "},
+  error! {r"
+     --> test.adoc:8:3
+      |
+    8 | [#dupe]
+      |   ^^^^ Duplicate id `dupe`, first declared on line 3
+  "}
+);
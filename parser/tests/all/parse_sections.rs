@@ -50,7 +50,8 @@ fn test_parse_section_w_reftext() {
           ..attr_list!(0..18)
         }),
         title: None,
-        start: 0
+        start: 0,
+        numeral: None,
       },
       level: 1,
       id: Some(bstr!("_foo")),
@@ -19,12 +19,16 @@ fn test_basic_toc() {
           level: 1,
           id: Some(bstr!("_sect_1")),
           title: just!("sect 1", 10..16),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![],
         },
         TocNode {
           level: 1,
           id: Some(bstr!("_sect_2")),
           title: just!("sect 2", 21..27),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![],
         },
       ],
@@ -74,10 +78,14 @@ fn test_nested_toc() {
           level: 1,
           id: Some(bstr!("_sect_1")),
           title: just!("sect 1", 35..41),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![TocNode {
             level: 2,
             id: Some(bstr!("_sect_1_1")),
             title: just!("sect 1.1", 47..55),
+            special_sect: None,
+            unnumbered: false,
             children: vecb![],
           }],
         },
@@ -85,6 +93,8 @@ fn test_nested_toc() {
           level: 1,
           id: Some(bstr!("_sect_2")),
           title: just!("sect 2", 60..66),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![],
         },
       ],
@@ -116,22 +126,32 @@ fn test_deeply_nested_toc() {
         level: 1,
         id: Some(bstr!("_sect_1")),
         title: just!("sect 1", 24..30),
+        special_sect: None,
+        unnumbered: false,
         children: vecb![TocNode {
           level: 2,
           id: Some(bstr!("_sect_1_1")),
           title: just!("sect 1.1", 36..44),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![TocNode {
             level: 3,
             id: Some(bstr!("_sect_1_1_1")),
             title: just!("sect 1.1.1", 51..61),
+            special_sect: None,
+            unnumbered: false,
             children: vecb![TocNode {
               level: 4,
               id: Some(bstr!("_sect_1_1_1_1")),
               title: just!("sect 1.1.1.1", 69..81),
+              special_sect: None,
+              unnumbered: false,
               children: vecb![TocNode {
                 level: 5,
                 id: Some(bstr!("_sect_1_1_1_1_1")),
                 title: just!("sect 1.1.1.1.1", 90..104),
+                special_sect: None,
+                unnumbered: false,
                 children: vecb![],
               }],
             },],
@@ -169,10 +189,14 @@ fn test_toc_depth() {
           level: 1,
           id: Some(bstr!("_sect_1")),
           title: just!("sect 1", 24..30),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![TocNode {
             level: 2,
             id: Some(bstr!("_sect_1_1")),
             title: just!("sect 1.1", 36..44),
+            special_sect: None,
+            unnumbered: false,
             children: vecb![],
           }],
         },
@@ -180,6 +204,8 @@ fn test_toc_depth() {
           level: 1,
           id: Some(bstr!("_sect_2")),
           title: just!("sect 2", 109..115),
+          special_sect: None,
+          unnumbered: false,
           children: vecb![],
         },
       ],
@@ -401,6 +401,19 @@ macro_rules! assert_error {
   };
 }
 
+#[macro_export]
+macro_rules! assert_warning {
+  ($name:ident, $input:expr, $expected:expr) => {
+    #[test]
+    fn $name() {
+      let mut parser = test_parser!($input);
+      let result = parser.parse().expect("expected parse success");
+      expect_eq!(result.warnings.len(), 1, from: $input);
+      expect_eq!(result.warnings[0].plain_text(), $expected, from: $input);
+    }
+  };
+}
+
 #[macro_export]
 macro_rules! assert_no_error {
   ($name:ident, $input:expr) => {